@@ -4,14 +4,25 @@ from __future__ import annotations
 import asyncio
 from asyncio import AbstractEventLoop
 import concurrent.futures
+from datetime import datetime
 import os
+import platform
+import signal
+import subprocess
 import sys
+import time
 from typing import Any
 
-from PySide6.QtCore import QUrl
+from PySide6.QtCore import QTimer, QUrl, qVersion
 from PySide6.QtGui import QIcon
 from PySide6.QtMultimedia import QAudioOutput, QMediaPlayer
-from PySide6.QtWidgets import QApplication, QMessageBox
+from PySide6.QtWebEngineCore import QWebEngineProfile
+from PySide6.QtWidgets import (
+    QApplication,
+    QFileDialog,
+    QMessageBox,
+    QSystemTrayIcon,
+)
 
 from systembridgeconnector.websocket_client import WebSocketClient
 from systembridgemodels.media_play import MediaPlay
@@ -27,11 +38,51 @@ from systembridgeshared.logger import setup_logger
 from systembridgeshared.settings import Settings
 
 from ._version import __version__
-from .system_tray import SystemTray
+from .common import fit_aspect_ratio, parse_aspect_ratio, parse_version
+from .connection_state import (
+    RECONNECT_MAX_ATTEMPTS,
+    ConnectionState,
+    ConnectionStateMachine,
+    get_reconnect_delay,
+)
+from .const import (
+    EXIT_CODE_ERROR,
+    EXIT_CODE_INVALID_DATA,
+    EXIT_CODE_NO_DATA,
+    EXIT_CODE_SUCCESS,
+    MIN_BACKEND_VERSION,
+    WINDOW_HEIGHT,
+    WINDOW_WIDTH,
+)
+from .log_file import LogFile
+from .pages import DEFAULT_PAGE, PAGES
+from .settings_watcher import SettingsWatcher
+from .single_instance import SingleInstance
+from .system_tray import SystemTray, TrayCallbacks
+from .widgets.about_dialog import AboutDialog
 from .widgets.timed_message_box import TimedMessageBox
+from .window.logs import LogsWindow
 from .window.main import MainWindow
 from .window.notification import NotificationWindow
 from .window.player import PlayerWindow
+from .window.popup import PopupWindow
+from .window_state import WindowState
+
+EVENT_LOOP_INTERVAL_MS = 100
+# How often CPU and memory usage are requested for the tray tooltip
+TRAY_STATS_INTERVAL_MS = 30000
+# Connection failures are expected for a while after launch, as the backend
+# may still be starting
+STARTUP_GRACE_PERIOD = 30
+# The system tray may not be up yet at login, so retry before giving up
+SYSTEM_TRAY_RETRY_DELAYS = [1, 2, 3, 4]
+
+TRAY_STATUS = {
+    ConnectionState.STARTING: "Connecting..",
+    ConnectionState.RECONNECTING: "Reconnecting..",
+    ConnectionState.DOWN: "Backend offline",
+    ConnectionState.STOPPED: "Stopped",
+}
 
 
 class Application(Base):
@@ -42,6 +93,9 @@ class Application(Base):
         settings: Settings,
         command: str = "main",
         data: dict | None = None,
+        page: str | None = None,
+        safe_mode: bool = False,
+        aspect_ratio: float | None = None,
     ) -> None:
         """Initialise."""
         setup_logger(settings.data.log_level, "system-bridge-gui")
@@ -50,7 +104,25 @@ class Application(Base):
 
         self._settings = settings
         self._data = ModulesData()
+        self._websocket_client: WebSocketClient | None = None
         self._websocket_listen_task: asyncio.Task | None = None
+        self._websocket_setup_task: asyncio.Task | None = None
+        self._connection_state = ConnectionStateMachine(
+            self._connection_state_changed
+        )
+        self._authentication_prompted = False
+        self._startup_summary_logged = False
+        self._startup_time = time.monotonic()
+        self._reconnect_attempts = 0
+        self._reconnect_handle: asyncio.TimerHandle | None = None
+        self._backend_version_warned: str | None = None
+        self._connection_settings: tuple[int, str] | None = None
+        # Notify once when a working connection drops, and again when it is back
+        self._backend_lost = False
+        self._expected_disconnect = False
+        self._system_tray: SystemTray | None = None
+        self._event_loop_timer: QTimer | None = None
+        self._tray_stats_timer: QTimer | None = None
 
         self._application = QApplication([])
         self._icon = QIcon(os.path.join(os.path.dirname(__file__), "icon.png"))
@@ -80,36 +152,125 @@ class Application(Base):
         if command == "main":
             self._logger.info("Main: Setup")
 
-            # Setup the main window
-            self._main_window = MainWindow(
-                self._settings,
-                self._icon,
+            # Hand over to an already running GUI rather than starting another
+            self._single_instance = SingleInstance()
+            message = page or ""
+            if aspect_ratio is not None:
+                message = f"{page or DEFAULT_PAGE} {aspect_ratio}"
+            if self._single_instance.send(message):
+                self._logger.info("GUI is already running, exiting")
+                sys.exit(EXIT_CODE_SUCCESS)
+            self._single_instance.listen()
+            self._single_instance.message_received.connect(  # type: ignore
+                self._callback_instance_message
             )
 
+            if safe_mode:
+                self._logger.info("Safe mode: ignoring saved state and cache")
+                self._clear_cache()
+                page = "settings"
+            self._window_state = WindowState(ignore_saved=safe_mode)
+
+            self._log_file = LogFile()
+            if not self._window_state.get_preference("file_logging"):
+                self._log_file.set_enabled(False)
+
+            # Page windows are created when first shown, keyed by path
+            self._page_windows: dict[str, MainWindow] = {}
+
+            # Setup the logs window
+            self._logs_window = LogsWindow(self._icon)
+
+            # macOS expects monochrome template images in the menu bar
+            tray_icon = QIcon(self._icon)
+            if sys.platform == "darwin":
+                tray_icon.setIsMask(True)
+
             # Setup the system tray
             self._system_tray = SystemTray(
                 self._settings,
-                self._icon,
+                self._window_state,
+                tray_icon,
                 self._application,
-                self._callback_exit_application,
-                self._callback_show_window,
+                TrayCallbacks(
+                    exit_application=self._callback_exit_application,
+                    show_window=self._callback_show_window,
+                    show_logs=self._callback_show_logs,
+                    hide_windows=self._callback_hide_windows,
+                    clear_cache=self._callback_clear_cache,
+                    open_devtools=self._callback_open_devtools,
+                    always_on_top=self._callback_always_on_top,
+                    check_connection=self._callback_check_connection,
+                    restart_safe_mode=self._callback_restart_safe_mode,
+                    save_screenshot=self._callback_save_screenshot,
+                    reset_window_state=self._callback_reset_window_state,
+                    reload=self._callback_reload,
+                    reset_window_geometry=self._callback_reset_window_geometry,
+                    show_about=self._callback_show_about,
+                    title_suffix_changed=self._callback_title_suffix_changed,
+                    file_logging=self._callback_file_logging,
+                ),
+            )
+            self._update_tray_status()
+            self._show_system_tray()
+
+            # Pages are given the OS theme, so reload them when it changes
+            self._application.styleHints().colorSchemeChanged.connect(  # type: ignore
+                self._callback_color_scheme_changed
+            )
+
+            # Watch the settings file for changes
+            self._settings_watcher = SettingsWatcher(self._settings)
+            self._settings_watcher.settings_changed.connect(  # type: ignore
+                self._callback_settings_changed
+            )
+
+            # Run the asyncio event loop alongside the Qt event loop
+            self._event_loop_timer = QTimer()
+            self._event_loop_timer.setInterval(EVENT_LOOP_INTERVAL_MS)
+            self._event_loop_timer.timeout.connect(  # type: ignore
+                self._process_event_loop
             )
-            self._system_tray.show()
+            self._event_loop_timer.start()
+
+            self._tray_stats_timer = QTimer()
+            self._tray_stats_timer.setInterval(TRAY_STATS_INTERVAL_MS)
+            self._tray_stats_timer.timeout.connect(  # type: ignore
+                self._request_tray_stats
+            )
+            self._tray_stats_timer.start()
+
+            # Shut down cleanly when asked to by the terminal or service manager
+            self._setup_signal_handlers()
 
             # Setup the WebSocket
-            self._websocket_client = WebSocketClient(
-                "localhost",
-                self._settings.data.api.port,
-                self._settings.data.api.token,
+            self._websocket_client = self._create_websocket_client()
+            self._websocket_setup_task = self._loop.create_task(self._setup_websocket())
+
+            if page is None and self._window_state.get_preference("open_on_startup"):
+                last_page = self._window_state.get("last_page")
+                # _open_page falls back to the default for pages that are gone
+                page = last_page if isinstance(last_page, str) else DEFAULT_PAGE
+            if page is None and aspect_ratio is not None:
+                page = DEFAULT_PAGE
+            if page is not None:
+                self._open_page(page, aspect_ratio)
+        elif command == "popup":
+            self._logger.info("Popup")
+            if data is None or page not in PAGES:
+                self._logger.error("Unknown page: %s", page)
+                self._startup_error(f"Unknown page: {page}", EXIT_CODE_INVALID_DATA)
+            self._main_window = self._open_popup(
+                str(page),
+                int(data["width"]),
+                int(data["height"]),
             )
-            self._loop.create_task(self._setup_websocket())
         elif command == "media-player-audio":
             self._logger.info("Media Player: Audio")
             if data is None:
                 self._logger.error("No data provided!")
-                self._startup_error("No data provided!")
-                sys.exit(1)
-            media_play = MediaPlay(**data)
+                self._startup_error("No data provided!", EXIT_CODE_NO_DATA)
+            media_play = self._parse_data(MediaPlay, data)
             self._main_window = PlayerWindow(
                 self._settings,
                 self._icon,
@@ -121,9 +282,8 @@ class Application(Base):
             self._logger.info("Media Player: Video")
             if data is None:
                 self._logger.error("No data provided!")
-                self._startup_error("No data provided!")
-                sys.exit(1)
-            media_play = MediaPlay(**data)
+                self._startup_error("No data provided!", EXIT_CODE_NO_DATA)
+            media_play = self._parse_data(MediaPlay, data)
             self._main_window = PlayerWindow(
                 self._settings,
                 self._icon,
@@ -135,10 +295,9 @@ class Application(Base):
             self._logger.info("Notification")
             if data is None:
                 self._logger.error("No data provided!")
-                self._startup_error("No data provided!")
-                sys.exit(1)
+                self._startup_error("No data provided!", EXIT_CODE_NO_DATA)
 
-            notification_data = NotificationData(**data)
+            notification_data = self._parse_data(NotificationData, data)
 
             self._main_window = NotificationWindow(
                 self._settings,
@@ -167,39 +326,422 @@ class Application(Base):
 
     def _callback_exit_application(self) -> None:
         """Exit the application."""
-        self._exit_application(0)
+        if self._window_state.get_preference("confirm_exit"):
+            result = QMessageBox.question(
+                None,  # type: ignore
+                "Exit",
+                "Exit System Bridge? This also stops the backend.",
+            )
+            if result != QMessageBox.StandardButton.Yes:
+                return
+
+        self._exit_application(EXIT_CODE_SUCCESS)
+
+    def _callback_settings_changed(self) -> None:
+        """Handle settings changes."""
+        self._logger.info("Settings reloaded")
+        if self._connection_settings == (
+            self._settings.data.api.port,
+            self._settings.data.api.token,
+        ):
+            return
+        if self._connection_state.state == ConnectionState.STOPPED:
+            return
+
+        self._logger.info("Port or token changed, reconnecting")
+        # Don't let an attempt with the old settings finish after this one
+        self._cancel_reconnect()
+        if self._websocket_setup_task:
+            self._websocket_setup_task.cancel()
+            self._websocket_setup_task = None
+        if self._websocket_listen_task:
+            self._websocket_listen_task.cancel()
+            self._websocket_listen_task = None
+        if self._websocket_client is not None and self._websocket_client.connected:
+            self._loop.create_task(self._websocket_client.close())
+
+        # Reconnecting re-navigates open windows with the new port and token
+        self._expected_disconnect = True
+        self._set_connection_state(ConnectionState.DOWN, "Settings changed")
+        self._expected_disconnect = False
+        self._reconnect_attempts = 0
+        self._reconnect()
+
+    def _callback_color_scheme_changed(self) -> None:
+        """Reload open page windows with the new theme."""
+        self._logger.info("Colour scheme changed")
+        for window in self._page_windows.values():
+            if window.isVisible() and window.path is not None:
+                window.setup(window.path)
 
     def _callback_show_window(
         self,
         path: str,
         maximized: bool,
-        width: int | None = 1280,
-        height: int | None = 720,
+        width: int | None = None,
+        height: int | None = None,
     ) -> None:
-        """Show the main window."""
+        """Show the window for a page."""
         self._logger.info("Showing window: %s", path)
 
+        default_width, default_height = self._default_window_size()
         if width is None:
-            width = 1280
+            width = default_width
         if height is None:
-            height = 720
-
-        self._main_window.hide()
-        self._main_window.setup(path)  # type: ignore
-        self._main_window.resize(width, height)
-        screen_geometry = self._application.primaryScreen().availableSize()
-        self._main_window.move(
-            int((screen_geometry.width() - self._main_window.width()) / 2),
-            int((screen_geometry.height() - self._main_window.height()) / 2),
+            height = default_height
+
+        window = self._page_windows.pop(path, None)
+        if window is None:
+            window = MainWindow(
+                self._settings,
+                self._icon,
+                self._window_state,
+            )
+            window.set_always_on_top(self._window_state.get("always_on_top", False))
+            window.exit_requested.connect(self._callback_window_closed)  # type: ignore
+        # Keep the most recently shown window last
+        self._page_windows[path] = window
+        for page_id, page in PAGES.items():
+            if page.path == path:
+                self._window_state.set("last_page", page_id)
+
+        # Focus an open window rather than reloading it
+        if not window.isVisible():
+            window.setup(path)
+            restored = window.restore_geometry()
+            if not restored:
+                window.resize(width, height)
+                screen_geometry = self._application.primaryScreen().availableSize()
+                window.move(
+                    int((screen_geometry.width() - window.width()) / 2),
+                    int((screen_geometry.height() - window.height()) / 2),
+                )
+            if maximized:
+                window.showMaximized()
+            elif restored:
+                window.show()
+            else:
+                window.showNormal()
+        # Bring the window in front of other applications
+        if self._window_state.get_preference("raise_on_show"):
+            window.raise_()
+            window.activateWindow()
+
+    def _default_window_size(self) -> tuple[int, int]:
+        """Get the preferred size for new windows, kept within the screen."""
+        size = self._window_state.get_preference("window_size")
+        if (
+            not isinstance(size, list)
+            or len(size) != 2
+            or not all(isinstance(value, int) and value > 0 for value in size)
+        ):
+            self._logger.warning("Ignoring invalid window size: %s", size)
+            size = [WINDOW_WIDTH, WINDOW_HEIGHT]
+
+        # A size larger than the screen would leave the title bar out of reach
+        available_size = self._application.primaryScreen().availableSize()
+        return (
+            min(size[0], available_size.width()),
+            min(size[1], available_size.height()),
         )
-        if maximized:
-            self._main_window.showMaximized()
+
+    def _active_window(self) -> MainWindow | None:
+        """Get the focused page window, or the most recently shown one."""
+        windows = [
+            window for window in self._page_windows.values() if window.isVisible()
+        ]
+        for window in windows:
+            if window.isActiveWindow():
+                return window
+        return windows[-1] if windows else None
+
+    def _require_active_window(
+        self,
+        action: str,
+    ) -> MainWindow | None:
+        """Get the active page window, asking for one to be opened if there isn't."""
+        window = self._active_window()
+        if window is None:
+            self._system_tray.showMessage(  # type: ignore
+                "System Bridge",
+                f"Open a window to {action}",
+            )
+        return window
+
+    def _callback_instance_message(
+        self,
+        message: str,
+    ) -> None:
+        """Handle another instance being launched."""
+        # Messages are a page id, optionally followed by an aspect ratio
+        page, _, aspect_ratio = message.partition(" ")
+        window = self._active_window()
+        if page:
+            self._open_page(page, parse_aspect_ratio(aspect_ratio))
+        elif window is not None:
+            window.raise_()
+            window.activateWindow()
         else:
-            self._main_window.showNormal()
+            self._open_page(DEFAULT_PAGE)
+
+    def _open_page(
+        self,
+        page: str,
+        aspect_ratio: float | None = None,
+    ) -> None:
+        """Open a page by its id, falling back to the default page."""
+        if page not in PAGES:
+            self._logger.warning("Unknown page: %s, using %s", page, DEFAULT_PAGE)
+            page = DEFAULT_PAGE
+        path = PAGES[page].path
+        self._callback_show_window(path, False)
+        if aspect_ratio is None:
+            return
+
+        # Qt can't lock the ratio of a top level window, so resizing stays free
+        width, height = fit_aspect_ratio(*self._default_window_size(), aspect_ratio)
+        self._logger.info(
+            "Sizing %s to %sx%s for aspect ratio %.2f",
+            path,
+            width,
+            height,
+            aspect_ratio,
+        )
+        window = self._page_windows[path]
+        window.showNormal()
+        window.resize(width, height)
+
+    def _open_popup(
+        self,
+        page: str,
+        width: int,
+        height: int,
+    ) -> PopupWindow:
+        """Open a page in a small undecorated window above other windows."""
+        path = PAGES[page].path
+        self._logger.info("Opening %s in a %sx%s popup", path, width, height)
+        return PopupWindow(
+            self._settings,
+            self._icon,
+            self._application,
+            path,
+            width,
+            height,
+        )
+
+    def _callback_show_logs(self) -> None:
+        """Show the logs window."""
+        self._logger.info("Showing logs window")
+        self._logs_window.setup()
+        self._logs_window.showNormal()
+        self._logs_window.raise_()
+        self._logs_window.activateWindow()
+
+    def _callback_hide_windows(self) -> None:
+        """Hide all windows to the tray."""
+        self._logger.info("Hiding windows")
+        for window in self._page_windows.values():
+            window.hide()
+        self._logs_window.hide()
+
+    def _callback_clear_cache(self) -> None:
+        """Clear the web view cache and cookies."""
+        result = QMessageBox.question(
+            None,  # type: ignore
+            "Clear Cache",
+            "This will clear cached pages and cookies for System Bridge. "
+            "You may need to re-enter anything you have stored. Continue?",
+        )
+        if result != QMessageBox.StandardButton.Yes:
+            return
+
+        self._clear_cache()
+        for window in self._page_windows.values():
+            if window.isVisible():
+                window.reload()
+
+    def _callback_restart_safe_mode(self) -> None:
+        """Restart the GUI in safe mode."""
+        self._restart(["--safe-mode"])
+
+    def _clear_cache(self) -> None:
+        """Clear the web view cache and cookies."""
+        self._logger.info("Clearing web view cache")
+        profile = QWebEngineProfile.defaultProfile()
+        profile.clearHttpCache()
+        profile.cookieStore().deleteAllCookies()
+
+    def _restart(
+        self,
+        arguments: list[str],
+    ) -> None:
+        """Restart the GUI, leaving the backend running."""
+        if getattr(sys, "frozen", False):
+            command = [sys.executable, "main", *arguments]
+        else:
+            command = [sys.executable, "-m", "systembridgegui", "main", *arguments]
+
+        self._logger.info("Restarting GUI: %s", command)
+        # Let the new process take over as the running instance
+        self._single_instance.close()
+        try:
+            subprocess.Popen(command)  # pylint: disable=consider-using-with
+        except OSError as exception:
+            self._logger.error("Could not restart the GUI: %s", exception)
+            self._single_instance.listen()
+            return
+        self._exit_application(EXIT_CODE_SUCCESS, stop_backend=False)
+
+    def _callback_open_devtools(self) -> None:
+        """Open the developer tools for the active window."""
+        window = self._require_active_window("inspect it")
+        if window is None:
+            return
+
+        self._logger.info("Opening developer tools")
+        window.open_devtools()
+
+    def _callback_always_on_top(
+        self,
+        enabled: bool,
+    ) -> None:
+        """Toggle whether the page windows stay on top."""
+        self._logger.info("Always on top: %s", enabled)
+        for window in self._page_windows.values():
+            window.set_always_on_top(enabled)
+        self._window_state.set("always_on_top", enabled)
+
+    def _callback_check_connection(self) -> None:
+        """Check the backend connection now."""
+        if self._connection_state.state == ConnectionState.CONNECTED:
+            self._system_tray.showMessage(  # type: ignore
+                "System Bridge",
+                "Connected to the backend",
+            )
+            return
+
+        self._logger.info("Checking backend connection now")
+        self._cancel_reconnect()
+        self._reconnect_attempts = 0
+        self._reconnect()
+
+    def _callback_reset_window_geometry(self) -> None:
+        """Restore the active window to its default size and centre it."""
+        window = self._require_active_window("reset it")
+        if window is None:
+            return
+
+        self._logger.info("Reset window size and position: %s", window.path)
+        self._window_state.set(window.geometry_key, None)
+        window.showNormal()
+        window.resize(*self._default_window_size())
+        # Centre on the screen the window is currently on
+        screen_geometry = window.screen().availableGeometry()
+        window.move(
+            screen_geometry.x() + int((screen_geometry.width() - window.width()) / 2),
+            screen_geometry.y()
+            + int((screen_geometry.height() - window.height()) / 2),
+        )
+
+    def _callback_reload(self) -> None:
+        """Reload the active window without recreating it."""
+        window = self._require_active_window("reload it")
+        if window is None:
+            return
+
+        self._logger.info("Reload window: %s", window.path)
+        window.reload()
+
+    def _callback_show_about(self) -> None:
+        """Show the about dialog."""
+        self._logger.info("Showing about dialog")
+        about_dialog = AboutDialog(
+            self._icon,
+            {
+                "GUI version": __version__.public(),
+                "Backend version": str(
+                    getattr(self._data.system, "version", None) or "unknown"
+                ),
+                "Backend": f"localhost:{self._settings.data.api.port}",
+                "Connection": str(self._connection_state.state),
+                "Operating system": platform.platform(),
+                "Qt version": qVersion(),
+            },
+            self._system_tray.copy_to_clipboard,  # type: ignore
+        )
+        about_dialog.exec()
+
+    def _callback_window_closed(self) -> None:
+        """Exit the GUI when a window is closed, leaving the backend running."""
+        self._logger.info("Window closed, exiting as minimize to tray is off")
+        self._exit_application(EXIT_CODE_SUCCESS, stop_backend=False)
+
+    def _callback_file_logging(
+        self,
+        enabled: bool,
+    ) -> None:
+        """Turn writing the GUI log file on or off."""
+        self._log_file.set_enabled(enabled)
+
+    def _callback_title_suffix_changed(self) -> None:
+        """Update the titles of open page windows."""
+        for window in self._page_windows.values():
+            window.update_title()
+
+    def _callback_reset_window_state(self) -> None:
+        """Reset the saved window state, including preferences."""
+        self._logger.info("Reset window state")
+        self._window_state.reset()
+        # Apply the defaults to what is already open, so the menu matches the file
+        for window in self._page_windows.values():
+            window.set_always_on_top(False)
+        self._system_tray.update_preferences()  # type: ignore
+        self._callback_title_suffix_changed()
+        self._log_file.set_enabled(self._window_state.get_preference("file_logging"))
+        self._system_tray.showMessage(  # type: ignore
+            "System Bridge",
+            "Preferences reset. Windows will open at the default size and position.",
+        )
+
+    def _callback_save_screenshot(self) -> None:
+        """Save a screenshot of the active window."""
+        window = self._require_active_window("take a screenshot")
+        if window is None:
+            return
+
+        path, _ = QFileDialog.getSaveFileName(
+            window,
+            "Save Screenshot",
+            f"system-bridge-{datetime.now().strftime('%Y%m%d-%H%M%S')}.png",
+            "PNG Image (*.png)",
+        )
+        if not path:
+            return
+
+        if not window.capture(path):
+            self._logger.warning("Could not save screenshot: %s", path)
+            QMessageBox.warning(
+                window,
+                "Error",
+                f"Could not save the screenshot to {path}",
+            )
+
+    def _parse_data(
+        self,
+        model: type[Any],
+        data: dict,
+    ) -> Any:
+        """Parse the provided data into a model."""
+        try:
+            return model(**data)
+        except TypeError as exception:
+            self._logger.error("Invalid data provided: %s", exception)
+            self._startup_error("Invalid data provided!", EXIT_CODE_INVALID_DATA)
 
     def _startup_error(
         self,
         message: str,
+        code: int = EXIT_CODE_ERROR,
     ) -> None:
         """Handle a startup error."""
         error_message = TimedMessageBox(
@@ -210,19 +752,26 @@ class Application(Base):
         error_message.setWindowTitle("Error")
         error_message.exec()
         # Exit cleanly
-        self._exit_application(1)
+        self._exit_application(code)
 
     def _exit_application(
         self,
-        code: int = 0,
+        code: int = EXIT_CODE_SUCCESS,
+        stop_backend: bool = True,
     ) -> None:
         """Exit the backend."""
         self._logger.info("Exit..")
+        self._cancel_reconnect()
+        self._connection_state.transition(ConnectionState.STOPPED)
+        if self._event_loop_timer is not None:
+            self._event_loop_timer.stop()
+        if self._tray_stats_timer is not None:
+            self._tray_stats_timer.stop()
 
-        if code == 0:
+        if code == EXIT_CODE_SUCCESS:
             if self._loop is not None:
                 try:
-                    if self._websocket_client is not None:
+                    if stop_backend and self._websocket_client is not None:
                         if not self._websocket_client.connected:
                             self._logger.info("Connect to backend..")
                             self._loop.run_until_complete(
@@ -257,7 +806,8 @@ class Application(Base):
                     self._logger.warning("Could not stop event loop: %s", exception)
 
         self._logger.info("Exit GUI..")
-        self._system_tray.hide()
+        if self._system_tray is not None:
+            self._system_tray.hide()
         self._application.exit(code)
         sys.exit(code)
 
@@ -270,24 +820,326 @@ class Application(Base):
         self._logger.debug("Set new data for: %s", module_name)
         setattr(self._data, module_name, module)
         self._system_tray.update_tray_data(self._data)
+        if module_name in (DataEnum.CPU.value, DataEnum.MEMORY.value):
+            self._update_tray_status()
 
     async def _listen_for_data(self) -> None:
         """Listen for events from the WebSocket."""
+        issue = "Connection closed"
         try:
             await self._websocket_client.listen(callback=self._handle_module)
         except asyncio.CancelledError:
             self._logger.info("WebSocket listener cancelled")
+            return
         except (
             ConnectionErrorException,
             ConnectionClosedException,
             ConnectionResetError,
         ) as exception:
             self._logger.warning("Connection closed to WebSocket: %s", exception)
+            issue = f"Connection closed: {exception}"
 
         if self._websocket_listen_task:
             self._websocket_listen_task.cancel()
             self._websocket_listen_task = None
 
+        self._set_connection_state(ConnectionState.DOWN, issue)
+
+    def _show_system_tray(
+        self,
+        attempt: int = 0,
+    ) -> None:
+        """Show the system tray, retrying while it is unavailable."""
+        if QSystemTrayIcon.isSystemTrayAvailable():
+            self._system_tray.show()  # type: ignore
+            return
+
+        if attempt < len(SYSTEM_TRAY_RETRY_DELAYS):
+            delay = SYSTEM_TRAY_RETRY_DELAYS[attempt]
+            self._logger.warning(
+                "System tray unavailable (attempt %s), retrying in %s seconds..",
+                attempt + 1,
+                delay,
+            )
+            QTimer.singleShot(delay * 1000, lambda: self._show_system_tray(attempt + 1))
+            return
+
+        self._logger.error("System tray unavailable, opening the data window instead")
+        self._open_page(DEFAULT_PAGE)
+
+    def _setup_signal_handlers(self) -> None:
+        """Set up handlers for termination signals."""
+        signals = [signal.SIGINT, signal.SIGTERM]
+        if hasattr(signal, "SIGBREAK"):
+            # Sent on Windows when the console window is closed
+            signals.append(signal.SIGBREAK)  # pylint: disable=no-member
+
+        for signal_number in signals:
+            signal.signal(signal_number, self._handle_signal)
+
+    def _handle_signal(
+        self,
+        signal_number: int,
+        _: Any,
+    ) -> None:
+        """Handle a termination signal."""
+        self._logger.info("Received signal %s", signal.Signals(signal_number).name)
+        # Exit from the Qt event loop rather than inside the signal handler, and
+        # leave the backend running as it may be managed separately
+        QTimer.singleShot(
+            0,
+            lambda: self._exit_application(EXIT_CODE_SUCCESS, stop_backend=False),
+        )
+
+    def _process_event_loop(self) -> None:
+        """Run any pending asyncio callbacks and return to Qt."""
+        if self._loop.is_closed() or self._loop.is_running():
+            return
+        self._loop.call_soon(self._loop.stop)
+        self._loop.run_forever()
+
+    def _create_websocket_client(self) -> WebSocketClient:
+        """Create a WebSocket client from the current settings."""
+        self._connection_settings = (
+            self._settings.data.api.port,
+            self._settings.data.api.token,
+        )
+        return WebSocketClient(
+            "localhost",
+            *self._connection_settings,
+        )
+
+    def _set_connection_state(
+        self,
+        state: ConnectionState,
+        issue: str | None = None,
+    ) -> None:
+        """Move the connection to a new state, recording any issue."""
+        if (
+            self._connection_state.transition(state)
+            and issue is not None
+            and self._system_tray is not None
+        ):
+            self._system_tray.add_issue(issue)
+
+    def _connection_state_changed(
+        self,
+        previous_state: ConnectionState,
+        state: ConnectionState,
+    ) -> None:
+        """Handle a change in the connection state."""
+        self._update_tray_status()
+        if state == ConnectionState.CONNECTED:
+            self._reconnect_attempts = 0
+            if self._system_tray is not None:
+                self._system_tray.clear_issues()
+            if previous_state == ConnectionState.RECONNECTING:
+                self._backend_recovered()
+            if self._backend_lost:
+                self._backend_lost = False
+                self._notify_backend_status("System Bridge backend reconnected")
+        elif state == ConnectionState.DOWN:
+            if (
+                previous_state == ConnectionState.CONNECTED
+                and not self._expected_disconnect
+                and self._system_tray is not None
+            ):
+                self._backend_lost = True
+                self._notify_backend_status(
+                    "System Bridge backend stopped",
+                    QSystemTrayIcon.MessageIcon.Warning,
+                )
+            if self._reconnect_attempts == RECONNECT_MAX_ATTEMPTS:
+                self._logger.error(
+                    "Backend unreachable after %s reconnect attempts, still retrying",
+                    self._reconnect_attempts,
+                )
+                if self._system_tray is not None:
+                    self._system_tray.add_issue("Backend unreachable, still retrying")
+            delay = get_reconnect_delay(self._reconnect_attempts)
+            self._reconnect_attempts += 1
+            self._logger.info("Reconnecting in %.1f seconds..", delay)
+            self._cancel_reconnect()
+            self._reconnect_handle = self._loop.call_later(delay, self._reconnect)
+
+    def _notify_backend_status(
+        self,
+        message: str,
+        icon: QSystemTrayIcon.MessageIcon = QSystemTrayIcon.MessageIcon.Information,
+    ) -> None:
+        """Show a notification about the backend, unless they are turned off."""
+        if self._system_tray is None:
+            return
+        if not self._window_state.get_preference("notifications_enabled"):
+            self._logger.debug("Notifications off, not showing: %s", message)
+            return
+        self._system_tray.showMessage("System Bridge", message, icon)
+
+    def _update_tray_status(self) -> None:
+        """Show the connection state in the tray tooltip."""
+        if self._system_tray is None:
+            return
+
+        state = self._connection_state.state
+        if state == ConnectionState.CONNECTED:
+            version = getattr(self._data.system, "version", None)
+            status = f"Connected (v{version})" if version else "Connected"
+        elif (
+            state == ConnectionState.DOWN
+            and self._reconnect_attempts >= RECONNECT_MAX_ATTEMPTS
+        ):
+            status = "Backend unreachable"
+        else:
+            status = TRAY_STATUS[state]
+
+        show_stats = self._window_state.get_preference("tray_stats")
+        if state == ConnectionState.CONNECTED and show_stats:
+            stats = self._get_tray_stats()
+            if stats:
+                status = f"{status}\n{stats}"
+        self._system_tray.set_status(status)
+
+    def _get_tray_stats(self) -> str | None:
+        """Summarise CPU and memory usage, leaving out anything not received."""
+        stats = []
+        cpu_usage = getattr(self._data.cpu, "usage", None)
+        if isinstance(cpu_usage, (int, float)):
+            stats.append(f"CPU {cpu_usage:.0f}%")
+        memory_usage = getattr(
+            getattr(self._data.memory, "virtual", None), "percent", None
+        )
+        if isinstance(memory_usage, (int, float)):
+            stats.append(f"RAM {memory_usage:.0f}%")
+        return " \u2022 ".join(stats) or None
+
+    def _request_tray_stats(self) -> None:
+        """Ask the backend for CPU and memory usage, if the tooltip shows them."""
+        if not self._window_state.get_preference("tray_stats"):
+            # Clears a summary left over from before the option was turned off
+            self._update_tray_status()
+            return
+        if (
+            self._connection_state.state != ConnectionState.CONNECTED
+            or self._websocket_client is None
+        ):
+            return
+        self._loop.create_task(self._get_tray_stats_data())
+
+    async def _get_tray_stats_data(self) -> None:
+        """Request CPU and memory data, which arrives through the listener."""
+        try:
+            await self._websocket_client.get_data(  # type: ignore
+                GetData(
+                    modules=[DataEnum.CPU.value, DataEnum.MEMORY.value],
+                )
+            )
+        except (
+            ConnectionErrorException,
+            ConnectionClosedException,
+            ConnectionResetError,
+        ) as exception:
+            # The listener notices a dropped connection and reconnects
+            self._logger.debug("Could not request tray stats: %s", exception)
+
+    def _cancel_reconnect(self) -> None:
+        """Cancel any scheduled reconnect attempt."""
+        if self._reconnect_handle is not None:
+            self._reconnect_handle.cancel()
+            self._reconnect_handle = None
+
+    def _reconnect(self) -> None:
+        """Reconnect to the backend WebSocket."""
+        self._cancel_reconnect()
+        if self._connection_state.state != ConnectionState.DOWN:
+            return
+        # Leave DOWN now, so a second manual or scheduled reconnect is ignored
+        self._set_connection_state(ConnectionState.RECONNECTING)
+        self._websocket_client = self._create_websocket_client()
+        self._websocket_setup_task = self._loop.create_task(self._setup_websocket())
+
+    def _authentication_failed(self) -> None:
+        """Handle the backend rejecting the token."""
+        # The backend may have rotated the token and written it to the settings file
+        try:
+            settings = Settings()
+        except (KeyError, OSError, TypeError, ValueError) as exception:
+            # A half-written file must not stop the connection reaching DOWN
+            self._logger.warning("Could not reload settings: %s", exception)
+        else:
+            token = settings.data.api.token
+            if token and token != self._settings.data.api.token:
+                self._logger.info("Token changed on disk, retrying with the new token")
+                self._settings.data = settings.data
+                self._websocket_client = self._create_websocket_client()
+                self._websocket_setup_task = self._loop.create_task(
+                    self._setup_websocket()
+                )
+                return
+
+        if not self._authentication_prompted and self._system_tray is not None:
+            self._authentication_prompted = True
+            self._system_tray.showMessage(
+                "System Bridge",
+                "Invalid API token. Check the token in Help > Edit Settings File "
+                "or regenerate it in the System Bridge backend.",
+                QSystemTrayIcon.MessageIcon.Warning,
+            )
+        self._set_connection_state(ConnectionState.DOWN, "Invalid API token")
+
+    def _log_startup_summary(self) -> None:
+        """Log a one-time summary of the running configuration."""
+        if self._startup_summary_logged:
+            return
+        self._startup_summary_logged = True
+
+        self._logger.info(
+            "Startup summary: gui=%s backend=%s host=localhost:%s autostart=%s log_level=%s platform=%s",
+            __version__.public(),
+            getattr(self._data.system, "version", None),
+            self._settings.data.api.port,
+            self._settings.data.autostart,
+            self._settings.data.log_level,
+            platform.platform(),
+        )
+
+    def _check_backend_version(self) -> None:
+        """Warn if the backend is older than this GUI supports."""
+        version = getattr(self._data.system, "version", None)
+        if version is None:
+            return
+        version = str(version)
+
+        parsed_version = parse_version(version)
+        if parsed_version is None:
+            self._logger.warning("Could not parse backend version: %s", version)
+            return
+
+        if parsed_version >= MIN_BACKEND_VERSION:
+            return
+
+        minimum_version = ".".join(str(part) for part in MIN_BACKEND_VERSION)
+        self._logger.warning(
+            "Backend version %s is older than the minimum supported %s",
+            version,
+            minimum_version,
+        )
+        if self._backend_version_warned == version or self._system_tray is None:
+            return
+        self._backend_version_warned = version
+        self._system_tray.showMessage(
+            "System Bridge",
+            f"Your System Bridge backend ({version}) is too old, "
+            f"please update to {minimum_version} or newer.",
+            QSystemTrayIcon.MessageIcon.Warning,
+        )
+
+    def _backend_recovered(self) -> None:
+        """Re-navigate open windows once the backend is back."""
+        self._logger.info("Backend recovered")
+        for window in self._page_windows.values():
+            if window.isVisible() and window.path is not None:
+                window.setup(window.path)
+
     def _setup_listener(self) -> None:
         """Set up the listener for the WebSocket."""
         self._logger.info("Setup WebSocket listener..")
@@ -323,6 +1175,9 @@ class Application(Base):
                 if not listen:
                     return
 
+                self._set_connection_state(ConnectionState.CONNECTED)
+                self._authentication_prompted = False
+
                 # Run the listener in a separate thread
                 with concurrent.futures.ThreadPoolExecutor() as executor:
                     self._loop.run_in_executor(executor, self._setup_listener)
@@ -338,21 +1193,56 @@ class Application(Base):
                 while self._data.system is None:
                     self._logger.info("Waiting for system data..")
                     await asyncio.sleep(1)
-        except (AuthenticationException, ConnectionErrorException) as exception:
-            self._logger.warning("Could not connect to WebSocket: %s", exception)
+
+                self._log_startup_summary()
+                self._check_backend_version()
+                self._update_tray_status()
+                self._request_tray_stats()
+        except AuthenticationException as exception:
+            self._logger.warning("Could not authenticate with WebSocket: %s", exception)
+
+            if self._websocket_listen_task:
+                self._websocket_listen_task.cancel()
+                self._websocket_listen_task = None
+
+            if listen:
+                self._authentication_failed()
+        except ConnectionErrorException as exception:
+            if (
+                not self._connection_state.has_connected
+                and time.monotonic() - self._startup_time < STARTUP_GRACE_PERIOD
+            ):
+                self._logger.debug("Backend not ready yet: %s", exception)
+            else:
+                self._logger.warning("Could not connect to WebSocket: %s", exception)
 
             if self._websocket_listen_task:
                 self._websocket_listen_task.cancel()
                 self._websocket_listen_task = None
+
+            if listen:
+                self._set_connection_state(
+                    ConnectionState.DOWN, f"Could not connect: {exception}"
+                )
         except (ConnectionClosedException, ConnectionResetError) as exception:
             self._logger.warning("Connection closed to WebSocket: %s", exception)
 
             if self._websocket_listen_task:
                 self._websocket_listen_task.cancel()
                 self._websocket_listen_task = None
+
+            if listen:
+                self._set_connection_state(
+                    ConnectionState.DOWN, f"Connection closed: {exception}"
+                )
         except asyncio.TimeoutError as exception:
             self._logger.error("Connection timeout to WebSocket: %s", exception)
 
             if self._websocket_listen_task:
                 self._websocket_listen_task.cancel()
                 self._websocket_listen_task = None
+
+            if listen:
+                self._set_connection_state(
+                    ConnectionState.DOWN, "Connection timed out"
+                )
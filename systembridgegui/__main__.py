@@ -3,29 +3,179 @@ from __future__ import annotations
 
 import asyncio
 import json
+import os
 import sys
+from typing import Optional
 
-from typer import Typer
+from typer import Exit, Option, Typer, echo
 
+from systembridgeshared.common import get_user_data_directory
+from systembridgeshared.exceptions import AuthenticationException
 from systembridgeshared.logger import setup_logger
 from systembridgeshared.settings import Settings
 
-from . import Application
+from ._version import __version__
+from .backend import get_backend_version
+from .common import parse_aspect_ratio
+from .const import (
+    EXIT_CODE_AUTHENTICATION_FAILED,
+    EXIT_CODE_BACKEND_UNREACHABLE,
+    EXIT_CODE_INVALID_DATA,
+    EXIT_CODE_SETTINGS_INVALID,
+    EXIT_CODE_SETTINGS_MISSING,
+)
+from .pages import PAGES
+from .web_engine import handle_import_error
+
+try:
+    from . import Application
+except ImportError as import_exception:
+    # Qt WebEngine fails to load when its system libraries are missing
+    handle_import_error(import_exception)
 
 asyncio.set_event_loop(asyncio.new_event_loop())
 
 app = Typer()
-settings = Settings()
 
-setup_logger(settings.data.log_level, "system-bridge-gui")
+
+class SettingsUnavailable(Exception):
+    """The settings file is missing or can't be read."""
+
+    def __init__(
+        self,
+        message: str,
+        exit_code: int,
+    ) -> None:
+        """Initialise."""
+        super().__init__(message)
+        self.exit_code = exit_code
+
+
+def _read_settings() -> Settings:
+    """Read the settings file."""
+    settings_path = os.path.join(get_user_data_directory(), "settings.json")
+    if not os.path.isfile(settings_path):
+        raise SettingsUnavailable(
+            f"Settings file not found: {settings_path}. Start the backend first.",
+            EXIT_CODE_SETTINGS_MISSING,
+        )
+
+    try:
+        return Settings()
+    except (KeyError, OSError, TypeError, ValueError) as exception:
+        raise SettingsUnavailable(
+            f"Invalid settings file {settings_path}: {exception}",
+            EXIT_CODE_SETTINGS_INVALID,
+        ) from exception
+
+
+def _load_settings() -> Settings:
+    """Load the settings and set up logging, exiting if they are unusable."""
+    try:
+        settings = _read_settings()
+    except SettingsUnavailable as exception:
+        echo(str(exception), err=True)
+        sys.exit(exception.exit_code)
+
+    setup_logger(settings.data.log_level, "system-bridge-gui")
+    return settings
+
+
+def _load_data(data: str) -> dict:
+    """Load the JSON data passed on the command line."""
+    try:
+        return json.loads(data)
+    except json.JSONDecodeError as exception:
+        echo(f"Invalid data: {exception}", err=True)
+        sys.exit(EXIT_CODE_INVALID_DATA)
+
+
+def _version_callback(value: bool) -> None:
+    """Print the GUI and backend versions."""
+    if not value:
+        return
+
+    echo(f"System Bridge GUI: {__version__.public()}")
+    # The GUI version is always printed, even without a usable settings file
+    try:
+        settings = _read_settings()
+    except SettingsUnavailable as exception:
+        echo(f"System Bridge Backend: unavailable ({exception})")
+        raise Exit() from None
+
+    try:
+        version = asyncio.get_event_loop().run_until_complete(
+            get_backend_version(settings)
+        )
+    except AuthenticationException:
+        echo("System Bridge Backend: the API token was rejected", err=True)
+        raise Exit() from None
+    if version is None:
+        echo("System Bridge Backend: unavailable (not reachable)")
+    else:
+        echo(f"System Bridge Backend: {version}")
+    raise Exit()
+
+
+@app.callback()
+def callback(
+    version: bool = Option(
+        False,
+        "--version",
+        callback=_version_callback,
+        is_eager=True,
+        help="Print the version and exit",
+    ),
+) -> None:
+    """System Bridge GUI."""
 
 
 @app.command(name="main", help="Run the main application")
-def main() -> None:
+def main(
+    page: Optional[str] = Option(
+        None,
+        "--page",
+        help=f"Open a page ({', '.join(PAGES)}) on launch",
+    ),
+    safe_mode: bool = Option(
+        False,
+        "--safe-mode",
+        help="Ignore saved window state, clear the cache and open settings",
+    ),
+    aspect_ratio: Optional[str] = Option(
+        None,
+        "--aspect-ratio",
+        help="Size the page window to an aspect ratio, such as 16:9",
+    ),
+) -> None:
     """Run the main application."""
+    ratio = None
+    if aspect_ratio is not None:
+        ratio = parse_aspect_ratio(aspect_ratio)
+        if ratio is None:
+            echo(f"Invalid aspect ratio: {aspect_ratio}", err=True)
+            sys.exit(EXIT_CODE_INVALID_DATA)
     Application(
-        settings,
+        _load_settings(),
         command="main",
+        page=page,
+        safe_mode=safe_mode,
+        aspect_ratio=ratio,
+    )
+
+
+@app.command(name="popup", help="Open a page in a small window on top")
+def popup(
+    page: str,
+    width: int = Option(360, "--width", min=1, help="Popup width"),
+    height: int = Option(240, "--height", min=1, help="Popup height"),
+) -> None:
+    """Open a page in a popup window."""
+    Application(
+        _load_settings(),
+        command="popup",
+        data={"width": width, "height": height},
+        page=page,
     )
 
 
@@ -36,9 +186,9 @@ def media_player(
 ) -> None:
     """Run the media player."""
     Application(
-        settings,
+        _load_settings(),
         command=f"media-player-{media_type}",
-        data=json.loads(data),
+        data=_load_data(data),
     )
 
 
@@ -48,15 +198,31 @@ def notification(
 ) -> None:
     """Show a notification."""
     Application(
-        settings,
+        _load_settings(),
         command="notification",
-        data=json.loads(data),
+        data=_load_data(data),
     )
 
 
+@app.command(name="backend-version", help="Print the backend version")
+def backend_version() -> None:
+    """Print the backend version."""
+    try:
+        version = asyncio.get_event_loop().run_until_complete(
+            get_backend_version(_load_settings())
+        )
+    except AuthenticationException:
+        echo("The backend rejected the API token", err=True)
+        sys.exit(EXIT_CODE_AUTHENTICATION_FAILED)
+    if version is None:
+        echo("Could not get the backend version", err=True)
+        sys.exit(EXIT_CODE_BACKEND_UNREACHABLE)
+    echo(version)
+
+
 if __name__ == "__main__":
     # If no arguments are passed, run the main application.
     if sys.argv[1:] == []:
-        main()
+        main(page=None, safe_mode=False, aspect_ratio=None)
     else:
         app()
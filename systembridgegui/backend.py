@@ -0,0 +1,65 @@
+"""Backend."""
+from __future__ import annotations
+
+import asyncio
+from typing import Any
+
+from systembridgeconnector.websocket_client import WebSocketClient
+from systembridgemodels.modules import DataEnum, GetData
+from systembridgeshared.exceptions import (
+    ConnectionClosedException,
+    ConnectionErrorException,
+)
+from systembridgeshared.settings import Settings
+
+
+async def get_backend_version(
+    settings: Settings,
+    timeout: float = 10,
+) -> str | None:
+    """Get the backend version.
+
+    Returns None if the backend is unreachable, and raises
+    AuthenticationException if it rejects the token.
+    """
+    websocket_client = WebSocketClient(
+        "localhost",
+        settings.data.api.port,
+        settings.data.api.token,
+    )
+    version: asyncio.Future[str | None] = asyncio.get_running_loop().create_future()
+
+    async def _handle_module(
+        module_name: str,
+        module: Any,
+    ) -> None:
+        """Handle data from the WebSocket client."""
+        if module_name == DataEnum.SYSTEM.value and not version.done():
+            value = getattr(module, "version", None)
+            version.set_result(None if value is None else str(value))
+
+    listen_task: asyncio.Task | None = None
+    try:
+        async with asyncio.timeout(timeout):
+            await websocket_client.connect()
+            listen_task = asyncio.create_task(
+                websocket_client.listen(callback=_handle_module)
+            )
+            await websocket_client.get_data(
+                GetData(
+                    modules=[DataEnum.SYSTEM.value],
+                )
+            )
+            return await version
+    except (
+        ConnectionErrorException,
+        ConnectionClosedException,
+        ConnectionResetError,
+        asyncio.TimeoutError,
+    ):
+        return None
+    finally:
+        if listen_task is not None:
+            listen_task.cancel()
+        if websocket_client.connected:
+            await websocket_client.close()
@@ -0,0 +1,79 @@
+"""Common."""
+from __future__ import annotations
+
+import re
+from typing import Any
+from urllib.parse import urlencode
+
+from PySide6.QtCore import Qt
+from PySide6.QtGui import QGuiApplication
+
+from systembridgeshared.const import QUERY_API_PORT, QUERY_TOKEN
+from systembridgeshared.settings import Settings
+
+from .const import BACKGROUND_COLORS
+
+
+def parse_version(version: str) -> tuple[int, int, int] | None:
+    """Parse the release part of a version, ignoring any suffix."""
+    match = re.match(r"^v?(\d+)\.(\d+)\.(\d+)", version.strip())
+    if match is None:
+        return None
+    return int(match.group(1)), int(match.group(2)), int(match.group(3))
+
+
+def parse_aspect_ratio(value: str) -> float | None:
+    """Parse an aspect ratio given as width:height or as a single number."""
+    width, separator, height = value.strip().partition(":")
+    try:
+        ratio = float(width) / float(height) if separator else float(width)
+    except (ValueError, ZeroDivisionError):
+        return None
+    return ratio if 0 < ratio < float("inf") else None
+
+
+def fit_aspect_ratio(
+    width: int,
+    height: int,
+    aspect_ratio: float,
+) -> tuple[int, int]:
+    """Get the largest size with the aspect ratio that fits within a size."""
+    if width / height > aspect_ratio:
+        return max(round(height * aspect_ratio), 1), height
+    return width, max(round(width / aspect_ratio), 1)
+
+
+def get_theme() -> str:
+    """Get the OS colour scheme, defaulting to light when it is unknown."""
+    if QGuiApplication.instance() is None:
+        return "light"
+    if QGuiApplication.styleHints().colorScheme() == Qt.ColorScheme.Dark:
+        return "dark"
+    return "light"
+
+
+def get_background_color() -> str:
+    """Get the web view background colour for the OS colour scheme."""
+    return BACKGROUND_COLORS[get_theme()]
+
+
+def get_base_url(settings: Settings) -> str:
+    """Get the base URL of the backend."""
+    return f"http://localhost:{settings.data.api.port}"
+
+
+def get_app_url(
+    settings: Settings,
+    path: str,
+    query: dict[str, Any] | None = None,
+) -> str:
+    """Get the URL for a backend app page."""
+    query_string = urlencode(
+        {
+            QUERY_TOKEN: settings.data.api.token,
+            QUERY_API_PORT: settings.data.api.port,
+            "theme": get_theme(),
+            **(query or {}),
+        }
+    )
+    return f"{get_base_url(settings)}{path}?{query_string}"
@@ -0,0 +1,105 @@
+"""Connection State."""
+from __future__ import annotations
+
+from collections.abc import Callable
+from enum import StrEnum
+import random
+
+from systembridgeshared.base import Base
+
+# Reconnect delays double after each failure, up to the maximum, with
+# jitter so several clients do not retry in step
+RECONNECT_INTERVAL = 5
+RECONNECT_INTERVAL_MAX = 60
+RECONNECT_JITTER = 0.2
+# Report the backend as unreachable after this many consecutive failures. Retries
+# carry on at the maximum interval, so a restarted backend is still picked up.
+RECONNECT_MAX_ATTEMPTS = 20
+
+
+class ConnectionState(StrEnum):
+    """Backend connection state."""
+
+    STARTING = "starting"
+    CONNECTED = "connected"
+    RECONNECTING = "reconnecting"
+    DOWN = "down"
+    STOPPED = "stopped"
+
+
+TRANSITIONS: dict[ConnectionState, set[ConnectionState]] = {
+    ConnectionState.STARTING: {
+        ConnectionState.CONNECTED,
+        ConnectionState.DOWN,
+        ConnectionState.STOPPED,
+    },
+    ConnectionState.CONNECTED: {
+        ConnectionState.DOWN,
+        ConnectionState.STOPPED,
+    },
+    ConnectionState.RECONNECTING: {
+        ConnectionState.CONNECTED,
+        ConnectionState.DOWN,
+        ConnectionState.STOPPED,
+    },
+    ConnectionState.DOWN: {
+        ConnectionState.RECONNECTING,
+        ConnectionState.STOPPED,
+    },
+    ConnectionState.STOPPED: set(),
+}
+
+
+def get_reconnect_delay(
+    attempts: int,
+    jitter: Callable[[float, float], float] = random.uniform,
+) -> float:
+    """Get the delay before the next reconnect attempt."""
+    # The exponent is capped as retries carry on indefinitely
+    delay = min(
+        RECONNECT_INTERVAL * 2 ** min(attempts, RECONNECT_MAX_ATTEMPTS),
+        RECONNECT_INTERVAL_MAX,
+    )
+    return delay * jitter(1 - RECONNECT_JITTER, 1 + RECONNECT_JITTER)
+
+
+class ConnectionStateMachine(Base):
+    """Single source of truth for the backend connection state."""
+
+    def __init__(
+        self,
+        callback: Callable[[ConnectionState, ConnectionState], None],
+    ) -> None:
+        """Initialise."""
+        super().__init__()
+        self._callback = callback
+        self._state = ConnectionState.STARTING
+        self.has_connected = False
+
+    @property
+    def state(self) -> ConnectionState:
+        """Get the current state."""
+        return self._state
+
+    def transition(
+        self,
+        state: ConnectionState,
+    ) -> bool:
+        """Move to a new state, if the transition is allowed."""
+        if state == self._state:
+            return False
+
+        if state not in TRANSITIONS[self._state]:
+            self._logger.debug(
+                "Ignoring connection state transition: %s -> %s", self._state, state
+            )
+            return False
+
+        previous_state = self._state
+        self._state = state
+        if state == ConnectionState.CONNECTED:
+            self.has_connected = True
+
+        self._logger.info("Connection state: %s -> %s", previous_state, state)
+        self._callback(previous_state, state)
+        return True
@@ -0,0 +1,25 @@
+"""Constants."""
+
+# Exit codes, so launchers can tell why the GUI exited
+EXIT_CODE_SUCCESS = 0
+EXIT_CODE_ERROR = 1
+EXIT_CODE_SETTINGS_MISSING = 2
+EXIT_CODE_SETTINGS_INVALID = 3
+EXIT_CODE_BACKEND_UNREACHABLE = 4
+EXIT_CODE_AUTHENTICATION_FAILED = 5
+EXIT_CODE_NO_DATA = 6
+EXIT_CODE_INVALID_DATA = 7
+
+# Default size for page windows
+WINDOW_WIDTH = 1280
+WINDOW_HEIGHT = 720
+
+# Oldest backend this GUI works with
+MIN_BACKEND_VERSION = (4, 0, 0)
+
+# Web view backgrounds for each theme, so pages don't flash another colour
+# while they load
+BACKGROUND_COLORS = {
+    "dark": "#212121",
+    "light": "#ffffff",
+}
@@ -0,0 +1,52 @@
+"""Log File."""
+from __future__ import annotations
+
+import logging
+import os
+
+from systembridgeshared.base import Base
+from systembridgeshared.common import get_user_data_directory
+
+
+class LogFile(Base):
+    """Turn writing the GUI log file on and off while running."""
+
+    def __init__(self) -> None:
+        """Initialise."""
+        super().__init__()
+        self._path = os.path.join(get_user_data_directory(), "system-bridge-gui.log")
+        # File handlers taken off their loggers, so they can be put back
+        self._removed: list[tuple[logging.Logger, logging.Handler]] = []
+
+    def _file_handlers(self) -> list[tuple[logging.Logger, logging.Handler]]:
+        """Find the handlers writing to the GUI log file."""
+        loggers = [logging.getLogger()] + [
+            logger
+            for logger in logging.Logger.manager.loggerDict.values()
+            if isinstance(logger, logging.Logger)
+        ]
+        return [
+            (logger, handler)
+            for logger in loggers
+            for handler in logger.handlers
+            if isinstance(handler, logging.FileHandler)
+            and os.path.normcase(handler.baseFilename) == os.path.normcase(self._path)
+        ]
+
+    def set_enabled(
+        self,
+        enabled: bool,
+    ) -> None:
+        """Add or remove the log file handlers, leaving console logging alone."""
+        if enabled:
+            for logger, handler in self._removed:
+                logger.addHandler(handler)
+            self._removed = []
+            self._logger.info("Log file enabled: %s", self._path)
+            return
+
+        self._logger.info("Log file disabled: %s", self._path)
+        for logger, handler in self._file_handlers():
+            handler.flush()
+            logger.removeHandler(handler)
+            self._removed.append((logger, handler))
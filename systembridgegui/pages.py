@@ -0,0 +1,35 @@
+"""Pages."""
+from __future__ import annotations
+
+from dataclasses import dataclass
+
+PATH_DATA = "/app/data.html"
+PATH_SETTINGS = "/app/settings.html"
+
+
+@dataclass(frozen=True)
+class Page:
+    """A backend app page that can be opened in its own window."""
+
+    title: str
+    menu_title: str
+    path: str
+
+
+# Pages shown in the tray menu, in menu order
+PAGES: dict[str, Page] = {
+    "settings": Page("Settings", "Open Settings", PATH_SETTINGS),
+    "data": Page("Data", "View Data", PATH_DATA),
+}
+DEFAULT_PAGE = "data"
+
+
+def get_page_title(
+    path: str,
+    suffix: str = "System Bridge",
+) -> str:
+    """Get the window title for a page path, leaving out an empty suffix."""
+    for page in PAGES.values():
+        if page.path == path:
+            return f"{page.title} | {suffix}" if suffix else page.title
+    return suffix or "System Bridge"
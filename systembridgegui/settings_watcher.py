@@ -0,0 +1,90 @@
+"""Settings Watcher."""
+from __future__ import annotations
+
+import os
+
+from PySide6.QtCore import QFileSystemWatcher, QObject, QTimer, Signal
+
+from systembridgeshared.base import Base
+from systembridgeshared.common import get_user_data_directory
+from systembridgeshared.settings import Settings
+
+DEBOUNCE_MS = 500
+
+
+def is_valid_settings(settings: Settings) -> bool:
+    """Check the settings have a usable API port and token."""
+    return 0 < settings.data.api.port < 65536 and bool(settings.data.api.token)
+
+
+class SettingsWatcher(Base, QObject):
+    """Reload settings when the settings file changes on disk."""
+
+    settings_changed = Signal()
+
+    def __init__(
+        self,
+        settings: Settings,
+    ) -> None:
+        """Initialise the settings watcher."""
+        Base.__init__(self)
+        QObject.__init__(self)
+
+        self._settings = settings
+        self._settings_path = os.path.join(get_user_data_directory(), "settings.json")
+
+        self._debounce_timer = QTimer(self)
+        self._debounce_timer.setSingleShot(True)
+        self._debounce_timer.setInterval(DEBOUNCE_MS)
+        self._debounce_timer.timeout.connect(self._reload)  # type: ignore
+
+        self._watcher = QFileSystemWatcher(self)
+        self._watcher.fileChanged.connect(self._file_changed)  # type: ignore
+        if not self._watcher.addPath(self._settings_path):
+            self._logger.warning(
+                "Could not watch settings file: %s", self._settings_path
+            )
+            return
+
+        self._logger.info("Watching settings file: %s", self._settings_path)
+
+    def _file_changed(
+        self,
+        path: str,
+    ) -> None:
+        """Handle a change to the settings file."""
+        self._logger.debug("Settings file changed: %s", path)
+        # Some editors replace the file rather than writing to it,
+        # which removes it from the watcher
+        if path not in self._watcher.files() and os.path.isfile(path):
+            self._watcher.addPath(path)
+        self._debounce_timer.start()
+
+    def _reload(self) -> None:
+        """Reload the settings from disk."""
+        if not os.path.isfile(self._settings_path):
+            self._logger.warning(
+                "Settings file no longer exists: %s", self._settings_path
+            )
+            return
+
+        if self._settings_path not in self._watcher.files():
+            self._watcher.addPath(self._settings_path)
+
+        try:
+            settings = Settings()
+        except (KeyError, OSError, TypeError, ValueError) as exception:
+            self._logger.warning("Could not reload settings: %s", exception)
+            return
+
+        if not is_valid_settings(settings):
+            self._logger.warning("Ignoring invalid settings: missing port or token")
+            return
+
+        if settings.data == self._settings.data:
+            self._logger.debug("Settings unchanged")
+            return
+
+        self._logger.info("Settings changed, applying")
+        self._settings.data = settings.data
+        self.settings_changed.emit()
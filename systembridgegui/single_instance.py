@@ -0,0 +1,115 @@
+"""Single Instance."""
+from __future__ import annotations
+
+import getpass
+import os
+import re
+
+from PySide6.QtCore import QObject, Signal
+from PySide6.QtNetwork import QAbstractSocket, QLocalServer, QLocalSocket
+
+from systembridgeshared.base import Base
+
+SERVER_NAME = "system-bridge-gui"
+TIMEOUT_MS = 1000
+
+
+def get_server_name() -> str:
+    """Get the server name for the current user, so users don't share a GUI."""
+    if hasattr(os, "getuid"):
+        user = str(os.getuid())
+    else:
+        try:
+            user = getpass.getuser()
+        except (KeyError, OSError):
+            user = "default"
+    # The name becomes a socket path on Unix and a pipe name on Windows
+    return f"{SERVER_NAME}-{re.sub(r'[^A-Za-z0-9_.-]', '_', user)}"
+
+
+class SingleInstance(Base, QObject):
+    """Ensure only one GUI is running, forwarding requests to it."""
+
+    message_received = Signal(str)
+
+    def __init__(self) -> None:
+        """Initialise the single instance guard."""
+        Base.__init__(self)
+        QObject.__init__(self)
+
+        self._name = get_server_name()
+        self._server: QLocalServer | None = None
+
+    def send(
+        self,
+        message: str,
+    ) -> bool:
+        """Send a message to the running instance, if there is one."""
+        socket = QLocalSocket()
+        socket.connectToServer(self._name)
+        if not socket.waitForConnected(TIMEOUT_MS):
+            return False
+
+        self._logger.info("Forwarding to the running instance: %s", message)
+        # Always write something, so an empty message can't be mistaken for a probe
+        socket.write(f"{message}\n".encode("utf-8"))
+        socket.waitForBytesWritten(TIMEOUT_MS)
+        socket.disconnectFromServer()
+        return True
+
+    def listen(self) -> bool:
+        """Listen for messages from later instances."""
+        self._server = QLocalServer(self)
+        self._server.newConnection.connect(self._new_connection)  # type: ignore
+        if self._server.listen(self._name):
+            return True
+
+        # A previous instance may have crashed and left the socket behind. Only
+        # remove it once nothing answers, so a busy instance is not taken over.
+        if (
+            self._server.serverError() == QAbstractSocket.SocketError.AddressInUseError
+            and not self._is_running()
+        ):
+            self._logger.info("Removing stale instance socket: %s", self._name)
+            QLocalServer.removeServer(self._name)
+            if self._server.listen(self._name):
+                return True
+
+        self._logger.warning(
+            "Could not listen for other instances: %s", self._server.errorString()
+        )
+        return False
+
+    def close(self) -> None:
+        """Stop listening, so a new instance can take over."""
+        if self._server is not None:
+            self._server.close()
+            self._server = None
+
+    def _is_running(self) -> bool:
+        """Check whether another instance is listening, without sending to it."""
+        socket = QLocalSocket()
+        socket.connectToServer(self._name)
+        if not socket.waitForConnected(TIMEOUT_MS):
+            return False
+        socket.disconnectFromServer()
+        return True
+
+    def _new_connection(self) -> None:
+        """Handle a connection from another instance."""
+        if self._server is None:
+            return
+
+        socket = self._server.nextPendingConnection()
+        if socket.bytesAvailable() == 0:
+            socket.waitForReadyRead(TIMEOUT_MS)
+        data = bytes(socket.readAll().data())
+        socket.disconnectFromServer()
+        socket.deleteLater()
+        if not data:
+            # Another instance checking whether this one is still running
+            return
+
+        message = data.decode("utf-8").strip()
+        self._logger.info("Message from another instance: %s", message)
+        self.message_received.emit(message)
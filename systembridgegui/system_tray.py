@@ -1,29 +1,72 @@
 """System Tray."""
 from __future__ import annotations
 
+from collections import deque
 from collections.abc import Callable
+from dataclasses import dataclass
+from datetime import datetime
+import logging
 import os
+import sys
+from typing import Any
+from urllib.parse import urlencode
 from webbrowser import open_new_tab
 
-from pyperclip import copy
-from PySide6.QtGui import QAction, QCursor, QIcon
-from PySide6.QtWidgets import QApplication, QMenu, QSystemTrayIcon
+from pyperclip import PyperclipException, copy
+from PySide6.QtCore import QUrl
+from PySide6.QtGui import QAction, QActionGroup, QCursor, QDesktopServices, QIcon
+from PySide6.QtWidgets import QApplication, QInputDialog, QMenu, QSystemTrayIcon
 
 from systembridgemodels.modules import ModulesData
 from systembridgeshared.base import Base
 from systembridgeshared.common import get_user_data_directory
+from systembridgeshared.const import QUERY_API_PORT
 from systembridgeshared.settings import Settings
 
+from .common import get_base_url
+from .const import WINDOW_HEIGHT, WINDOW_WIDTH
+from .pages import PAGES, PATH_DATA
+from .window_state import WindowState
+
 PATH_BRIDGES_OPEN_ON = "/app/bridges/openon.html"
 PATH_BRIDGES_SETUP = "/app/bridges/setup.html"
-PATH_DATA = "/app/data.html"
-PATH_SETTINGS = "/app/settings.html"
 
 URL_DISCUSSIONS = "https://github.com/timmo001/system-bridge/discussions"
 URL_DOCS = "https://system-bridge.timmo.dev"
 URL_ISSUES = "https://github.com/timmo001/system-bridge/issues/new/choose"
 URL_LATEST_RELEASE = "https://github.com/timmo001/system-bridge/releases/latest"
 
+MAX_RECENT_ISSUES = 5
+
+WINDOW_SIZES = [
+    [1024, 576],
+    [WINDOW_WIDTH, WINDOW_HEIGHT],
+    [1600, 900],
+    [1920, 1080],
+]
+
+
+@dataclass
+class TrayCallbacks:
+    """Application actions triggered from the tray menu."""
+
+    exit_application: Callable[[], None]
+    show_window: Callable[[str, bool], None]
+    show_logs: Callable[[], None]
+    hide_windows: Callable[[], None]
+    clear_cache: Callable[[], None]
+    open_devtools: Callable[[], None]
+    always_on_top: Callable[[bool], None]
+    check_connection: Callable[[], None]
+    restart_safe_mode: Callable[[], None]
+    save_screenshot: Callable[[], None]
+    reset_window_state: Callable[[], None]
+    reload: Callable[[], None]
+    reset_window_geometry: Callable[[], None]
+    show_about: Callable[[], None]
+    title_suffix_changed: Callable[[], None]
+    file_logging: Callable[[bool], None]
+
 
 class SystemTray(Base, QSystemTrayIcon):
     """System Tray."""
@@ -32,35 +75,109 @@ class SystemTray(Base, QSystemTrayIcon):
     def __init__(
         self,
         settings: Settings,
+        window_state: WindowState,
         icon: QIcon,
         parent: QApplication,
-        callback_exit_application: Callable,
-        callback_show_window: Callable[[str, bool, int | None, int | None], None],
+        callbacks: TrayCallbacks,
     ) -> None:
         """Initialise the system tray."""
         Base.__init__(self)
         QSystemTrayIcon.__init__(self, icon, parent)
 
         self._settings = settings
+        self._window_state = window_state
+        self._preference_actions: dict[str, QAction] = {}
+        self._preference_choices: list[tuple[str, Any, QAction]] = []
+        self._callbacks = callbacks
 
         self._logger.info("Setup system tray")
 
-        self.callback_show_window = callback_show_window
+        self._recent_issues: deque[tuple[datetime, str]] = deque(
+            maxlen=MAX_RECENT_ISSUES
+        )
 
         self.activated.connect(self._on_activated)  # type: ignore
+        self.set_status(None)
 
         menu = QMenu()
 
-        action_settings: QAction = menu.addAction("Open Settings")
-        action_settings.triggered.connect(self._show_settings)  # type: ignore
+        for page_id, page in PAGES.items():
+            action_page: QAction = menu.addAction(page.menu_title)
+            action_page.triggered.connect(  # type: ignore
+                lambda *_, page_id=page_id: self._show_page(page_id)
+            )
+
+        action_browser: QAction = menu.addAction("Open in Browser")
+        action_browser.triggered.connect(self._open_in_browser)  # type: ignore
+
+        action_hide: QAction = menu.addAction("Hide Windows")
+        action_hide.triggered.connect(callbacks.hide_windows)  # type: ignore
+
+        action_reset_geometry: QAction = menu.addAction("Reset Window Size/Position")
+        action_reset_geometry.triggered.connect(  # type: ignore
+            callbacks.reset_window_geometry
+        )
+
+        self._action_always_on_top: QAction = menu.addAction("Always on Top")
+        self._action_always_on_top.setCheckable(True)
+        self._action_always_on_top.setChecked(
+            self._window_state.get("always_on_top", False)
+        )
+        self._action_always_on_top.toggled.connect(callbacks.always_on_top)  # type: ignore
+
+        menu_preferences = menu.addMenu("Preferences")
 
-        action_data: QAction = menu.addAction("View Data")
-        action_data.triggered.connect(self._show_data)  # type: ignore
+        self._add_preference_toggle(
+            menu_preferences, "Confirm Before Exiting", "confirm_exit"
+        )
+        self._add_preference_toggle(
+            menu_preferences, "Bring Windows to Front", "raise_on_show"
+        )
+        self._add_preference_toggle(
+            menu_preferences, "Keep Running When Windows Close", "minimize_to_tray"
+        )
+        self._add_preference_toggle(
+            menu_preferences, "Backend Status Notifications", "notifications_enabled"
+        )
+        self._add_preference_toggle(
+            menu_preferences, "Show CPU and Memory in Tooltip", "tray_stats"
+        )
+        self._add_preference_toggle(
+            menu_preferences, "Open Last Page on Launch", "open_on_startup"
+        )
+        self._add_preference_toggle(
+            menu_preferences,
+            "Write GUI Log File",
+            "file_logging",
+            callbacks.file_logging,
+        )
+
+        action_title_suffix: QAction = menu_preferences.addAction(
+            "Window Title Suffix.."
+        )
+        action_title_suffix.triggered.connect(self._edit_title_suffix)  # type: ignore
+
+        self._add_preference_choices(
+            menu_preferences.addMenu("Default Window Size"),
+            "window_size",
+            {f"{width} x {height}": [width, height] for width, height in WINDOW_SIZES},
+        )
+
+        self._add_preference_choices(
+            menu_preferences.addMenu("Hide Data Window When Unfocused"),
+            "auto_hide_after_secs",
+            {
+                "Never": 0,
+                "After 1 Minute": 60,
+                "After 5 Minutes": 300,
+                "After 15 Minutes": 900,
+            },
+        )
 
         menu.addSeparator()
 
-        action_latest_release: QAction = menu.addAction("Check for Updates")
-        action_latest_release.triggered.connect(self._open_latest_releases)  # type: ignore
+        self._action_latest_release: QAction = menu.addAction("Check for Updates")
+        self._action_latest_release.triggered.connect(self._open_latest_releases)  # type: ignore
 
         menu_help = menu.addMenu("Help")
 
@@ -76,11 +193,17 @@ class SystemTray(Base, QSystemTrayIcon):
         action_discussions: QAction = menu_help.addAction("Discussions")
         action_discussions.triggered.connect(self._open_discussions)  # type: ignore
 
+        action_about: QAction = menu_help.addAction("About")
+        action_about.triggered.connect(callbacks.show_about)  # type: ignore
+
         menu_help.addSeparator()
 
         action_token: QAction = menu_help.addAction("Copy Token to clipboard")
         action_token.triggered.connect(self._copy_token)  # type: ignore
 
+        action_base_url: QAction = menu_help.addAction("Copy Base URL to clipboard")
+        action_base_url.triggered.connect(self._copy_base_url)  # type: ignore
+
         menu_help.addSeparator()
 
         action_log: QAction = menu_help.addAction("Open Backend Logs")
@@ -89,13 +212,140 @@ class SystemTray(Base, QSystemTrayIcon):
         action_log_gui: QAction = menu_help.addAction("Open GUI Logs")
         action_log_gui.triggered.connect(self._open_gui_logs)  # type: ignore
 
+        action_edit_settings: QAction = menu_help.addAction("Edit Settings File")
+        action_edit_settings.triggered.connect(self._edit_settings_file)  # type: ignore
+
+        action_install_folder: QAction = menu_help.addAction("Open Install Folder")
+        action_install_folder.triggered.connect(self._open_install_folder)  # type: ignore
+
+        action_view_log_gui: QAction = menu_help.addAction("View GUI Logs")
+        action_view_log_gui.triggered.connect(callbacks.show_logs)  # type: ignore
+
+        self._menu_recent_issues = menu.addMenu("Recent Issues")
+        self._update_recent_issues()
+
+        menu_troubleshoot = menu.addMenu("Troubleshoot")
+
+        action_logs_folder: QAction = menu_troubleshoot.addAction("Open Logs Folder")
+        action_logs_folder.triggered.connect(self._open_data_folder)  # type: ignore
+
+        action_check_connection: QAction = menu_troubleshoot.addAction(
+            "Check Connection"
+        )
+        action_check_connection.triggered.connect(callbacks.check_connection)  # type: ignore
+
+        action_reload: QAction = menu_troubleshoot.addAction("Reload Window")
+        action_reload.triggered.connect(callbacks.reload)  # type: ignore
+
+        action_clear_cache: QAction = menu_troubleshoot.addAction("Clear Cache")
+        action_clear_cache.triggered.connect(callbacks.clear_cache)  # type: ignore
+
+        action_screenshot: QAction = menu_troubleshoot.addAction("Save Screenshot")
+        action_screenshot.triggered.connect(callbacks.save_screenshot)  # type: ignore
+
+        action_reset_window_state: QAction = menu_troubleshoot.addAction(
+            "Reset Window State"
+        )
+        action_reset_window_state.triggered.connect(  # type: ignore
+            callbacks.reset_window_state
+        )
+
+        action_clear_log: QAction = menu_troubleshoot.addAction("Clear GUI Log")
+        action_clear_log.triggered.connect(self._clear_gui_log)  # type: ignore
+
+        action_safe_mode: QAction = menu_troubleshoot.addAction(
+            "Restart in Safe Mode"
+        )
+        action_safe_mode.triggered.connect(callbacks.restart_safe_mode)  # type: ignore
+
+        # Only offer developer tools when debug logging is enabled
+        if self._logger.isEnabledFor(logging.DEBUG):
+            action_devtools: QAction = menu_troubleshoot.addAction(
+                "Open Developer Tools"
+            )
+            action_devtools.triggered.connect(callbacks.open_devtools)  # type: ignore
+
         menu.addSeparator()
 
         action_exit: QAction = menu.addAction("Exit")
-        action_exit.triggered.connect(callback_exit_application)  # type: ignore
+        action_exit.triggered.connect(callbacks.exit_application)  # type: ignore
 
         self.setContextMenu(menu)
 
+    def _add_preference_toggle(
+        self,
+        menu: QMenu,
+        title: str,
+        key: str,
+        callback: Callable[[bool], None] | None = None,
+    ) -> None:
+        """Add a checkable menu item for an on/off preference."""
+        action: QAction = menu.addAction(title)
+        action.setCheckable(True)
+        action.setChecked(bool(self._window_state.get_preference(key)))
+        action.toggled.connect(  # type: ignore
+            lambda checked, key=key: self._set_preference(key, checked)
+        )
+        if callback is not None:
+            action.toggled.connect(callback)  # type: ignore
+        self._preference_actions[key] = action
+
+    def _add_preference_choices(
+        self,
+        menu: QMenu,
+        key: str,
+        choices: dict[str, Any],
+    ) -> None:
+        """Add a menu item for each value a preference can be set to."""
+        group = QActionGroup(menu)
+        current = self._window_state.get_preference(key)
+        for title, value in choices.items():
+            action: QAction = menu.addAction(title)
+            action.setCheckable(True)
+            action.setChecked(value == current)
+            action.triggered.connect(  # type: ignore
+                lambda *_, key=key, value=value: self._set_preference(key, value)
+            )
+            group.addAction(action)
+            self._preference_choices.append((key, value, action))
+
+    def _set_preference(
+        self,
+        key: str,
+        value: Any,
+    ) -> None:
+        """Save a preference chosen from the menu."""
+        self._logger.info("Preference %s: %s", key, value)
+        self._window_state.set(key, value)
+
+    def _edit_title_suffix(self) -> None:
+        """Ask for the text shown after the page title in window titles."""
+        suffix, ok = QInputDialog.getText(
+            None,  # type: ignore
+            "Window Title Suffix",
+            "Shown after the page title. Leave empty to show only the page title.",
+            text=self._window_state.get_preference("title_suffix"),
+        )
+        if not ok:
+            return
+
+        self._set_preference("title_suffix", suffix.strip())
+        self._callbacks.title_suffix_changed()
+
+    def update_preferences(self) -> None:
+        """Show the current preferences in the menu, after a reset."""
+        self._action_always_on_top.blockSignals(True)
+        self._action_always_on_top.setChecked(
+            self._window_state.get("always_on_top", False)
+        )
+        self._action_always_on_top.blockSignals(False)
+        for key, action in self._preference_actions.items():
+            action.blockSignals(True)
+            action.setChecked(bool(self._window_state.get_preference(key)))
+            action.blockSignals(False)
+        for key, value, action in self._preference_choices:
+            action.setChecked(value == self._window_state.get_preference(key))
+
     def _on_activated(
         self,
         reason: int,
@@ -107,7 +357,41 @@ class SystemTray(Base, QSystemTrayIcon):
     def _copy_token(self) -> None:
         """Copy Token to clipboard."""
         self._logger.info("Copy Token to clipboard")
-        copy(self._settings.data.api.token)
+        self.copy_to_clipboard(self._settings.data.api.token)
+
+    def _copy_base_url(self) -> None:
+        """Copy Base URL to clipboard."""
+        self._logger.info("Copy Base URL to clipboard")
+        self.copy_to_clipboard(get_base_url(self._settings))
+
+    def copy_to_clipboard(
+        self,
+        text: str,
+    ) -> None:
+        """Copy text to the clipboard, if one is available."""
+        try:
+            copy(text)
+        except PyperclipException as exception:
+            self._logger.warning("Could not copy to clipboard: %s", exception)
+            self.showMessage(
+                "System Bridge",
+                "Clipboard unavailable",
+                QSystemTrayIcon.MessageIcon.Warning,
+            )
+            return
+
+        self.showMessage("System Bridge", "Copied to clipboard")
+
+    def _open_in_browser(self) -> None:
+        """Open the data page in the default browser."""
+        self._logger.info("Open data page in browser")
+        # Leave the token out, so it isn't kept in browser history or synced
+        query_string = urlencode({QUERY_API_PORT: self._settings.data.api.port})
+        open_new_tab(f"{get_base_url(self._settings)}{PATH_DATA}?{query_string}")
+        self.showMessage(
+            "System Bridge",
+            "Enter your token in the browser. Copy it from Help > Copy Token.",
+        )
 
     def _open_latest_releases(self) -> None:
         """Open latest release."""
@@ -146,13 +430,94 @@ class SystemTray(Base, QSystemTrayIcon):
         self._logger.info("Open: %s", log_path)
         open_new_tab(log_path)
 
-    def _show_data(self) -> None:
-        """Show api data."""
-        self.callback_show_window(PATH_DATA, False)  # type: ignore
+    def _open_data_folder(self) -> None:
+        """Open the folder containing the settings and log files."""
+        self._open_folder(get_user_data_directory())
+
+    def _open_install_folder(self) -> None:
+        """Open the folder the GUI is installed in."""
+        if getattr(sys, "frozen", False):
+            # Bundled builds run from an executable next to their resources
+            self._open_folder(os.path.dirname(sys.executable))
+        else:
+            self._open_folder(os.path.dirname(os.path.abspath(__file__)))
+
+    def _open_folder(
+        self,
+        path: str,
+    ) -> None:
+        """Open a folder in the file manager, if it exists."""
+        if not os.path.isdir(path):
+            self._logger.warning("Folder does not exist: %s", path)
+            self.showMessage(
+                "System Bridge",
+                f"{path} does not exist yet",
+                QSystemTrayIcon.MessageIcon.Warning,
+            )
+            return
+
+        self._logger.info("Open: %s", path)
+        QDesktopServices.openUrl(QUrl.fromLocalFile(path))
+
+    def _clear_gui_log(self) -> None:
+        """Clear the GUI log file."""
+        log_path = os.path.join(get_user_data_directory(), "system-bridge-gui.log")
+        self._logger.info("Clear: %s", log_path)
+        try:
+            with open(log_path, "w", encoding="utf-8"):
+                pass
+        except OSError as exception:
+            self._logger.warning("Could not clear log file: %s", exception)
+
+    def _edit_settings_file(self) -> None:
+        """Open the settings file in the default editor."""
+        settings_path = os.path.join(get_user_data_directory(), "settings.json")
+        self._logger.info("Open: %s", settings_path)
+        if not QDesktopServices.openUrl(QUrl.fromLocalFile(settings_path)):
+            # No application is associated with .json files
+            self._logger.info("Open: %s", get_user_data_directory())
+            QDesktopServices.openUrl(QUrl.fromLocalFile(get_user_data_directory()))
+
+    def _show_page(
+        self,
+        page_id: str,
+    ) -> None:
+        """Show a page."""
+        self._callbacks.show_window(PAGES[page_id].path, False)
+
+    def _update_recent_issues(self) -> None:
+        """Rebuild the recent issues menu."""
+        self._menu_recent_issues.clear()
+        if not self._recent_issues:
+            self._menu_recent_issues.addAction("No recent issues").setEnabled(False)
+            return
+
+        for timestamp, message in reversed(self._recent_issues):
+            self._menu_recent_issues.addAction(
+                f"{timestamp.strftime('%H:%M:%S')} - {message}"
+            ).setEnabled(False)
+
+    def set_status(
+        self,
+        status: str | None,
+    ) -> None:
+        """Show the connection status in the tooltip."""
+        self.setToolTip(f"System Bridge - {status}" if status else "System Bridge")
+
+    def add_issue(
+        self,
+        message: str,
+    ) -> None:
+        """Record a backend connection issue."""
+        self._recent_issues.append((datetime.now(), message))
+        self._update_recent_issues()
 
-    def _show_settings(self) -> None:
-        """Show settings."""
-        self.callback_show_window(PATH_SETTINGS, False)  # type: ignore
+    def clear_issues(self) -> None:
+        """Clear the recorded backend connection issues."""
+        if not self._recent_issues:
+            return
+        self._recent_issues.clear()
+        self._update_recent_issues()
 
     def update_tray_data(
         self,
@@ -163,15 +528,15 @@ class SystemTray(Base, QSystemTrayIcon):
 
         latest_version_text = "Check for Updates"
 
-        version_current = getattr(data.system, "version") if data.system else None
-        version_latest = getattr(data.system, "version_latest") if data.system else None
-        version_newer_available = (
-            getattr(data.system, "version_newer_available") if data.system else None
-        )
+        version_current = getattr(data.system, "version", None)
+        version_latest = getattr(data.system, "version_latest", None)
+        version_newer_available = getattr(data.system, "version_newer_available", None)
 
         if version_newer_available is not None:
-            latest_version_text = f"New version avaliable ({version_latest})"
+            latest_version_text = (
+                f"New version avaliable ({version_latest or 'unknown'})"
+            )
         elif version_current is not None:
             latest_version_text = f"Up to date ({version_current})"
 
-        self.contextMenu().actions()[3].setText(latest_version_text)
+        self._action_latest_release.setText(latest_version_text)
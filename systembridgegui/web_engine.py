@@ -0,0 +1,39 @@
+"""Web Engine."""
+from __future__ import annotations
+
+import importlib
+import sys
+from typing import NoReturn
+
+from PySide6.QtCore import Qt
+from PySide6.QtWidgets import QApplication, QMessageBox
+
+from .const import EXIT_CODE_ERROR
+
+WEB_ENGINE_HELP_URL = "https://doc.qt.io/qt-6/qtwebengine-platform-notes.html"
+
+
+def handle_import_error(exception: ImportError) -> NoReturn:
+    """Explain a missing Qt WebEngine in a dialog, or re-raise other errors."""
+    try:
+        importlib.import_module("PySide6.QtWebEngineWidgets")
+    except ImportError as web_engine_exception:
+        message = (
+            "System Bridge needs Qt WebEngine to show its windows, "
+            f"but it could not be loaded: {web_engine_exception}"
+        )
+    else:
+        raise exception
+
+    print(message, file=sys.stderr)
+    application = QApplication.instance() or QApplication([])
+    dialog = QMessageBox(
+        QMessageBox.Icon.Critical,
+        "System Bridge",
+        f"{message}<br><br>Install the system libraries it needs, see "
+        f'<a href="{WEB_ENGINE_HELP_URL}">Qt WebEngine platform notes</a>.',
+    )
+    dialog.setTextFormat(Qt.TextFormat.RichText)
+    dialog.exec()
+    application.quit()
+    sys.exit(EXIT_CODE_ERROR)
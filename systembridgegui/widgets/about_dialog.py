@@ -0,0 +1,47 @@
+"""About Dialog."""
+from __future__ import annotations
+
+from collections.abc import Callable
+
+from PySide6.QtCore import Qt
+from PySide6.QtGui import QIcon
+from PySide6.QtWidgets import QDialog, QDialogButtonBox, QLabel, QVBoxLayout
+
+
+class AboutDialog(QDialog):
+    """Show version and diagnostic information."""
+
+    def __init__(
+        self,
+        icon: QIcon,
+        diagnostics: dict[str, str],
+        callback_copy: Callable[[str], None],
+    ) -> None:
+        """Initialise."""
+        super().__init__()
+
+        self._diagnostics = "\n".join(
+            f"{name}: {value}" for name, value in diagnostics.items()
+        )
+        self._callback_copy = callback_copy
+
+        self.setWindowTitle("About System Bridge")
+        self.setWindowIcon(icon)
+
+        layout = QVBoxLayout(self)
+
+        label = QLabel(self._diagnostics)
+        label.setTextInteractionFlags(Qt.TextInteractionFlag.TextSelectableByMouse)
+        layout.addWidget(label)
+
+        buttons = QDialogButtonBox(QDialogButtonBox.StandardButton.Close)
+        button_copy = buttons.addButton(
+            "Copy Diagnostics", QDialogButtonBox.ButtonRole.ActionRole
+        )
+        button_copy.clicked.connect(self._copy)  # type: ignore
+        buttons.rejected.connect(self.reject)  # type: ignore
+        layout.addWidget(buttons)
+
+    def _copy(self) -> None:
+        """Copy the diagnostics to the clipboard."""
+        self._callback_copy(self._diagnostics)
@@ -0,0 +1,82 @@
+"""Logs window."""
+import os
+
+from PySide6.QtCore import QTimer
+from PySide6.QtGui import QCloseEvent, QFontDatabase, QIcon
+from PySide6.QtWidgets import QFrame, QPlainTextEdit, QVBoxLayout
+
+from systembridgeshared.base import Base
+from systembridgeshared.common import get_user_data_directory
+
+MAX_LINES = 5000
+
+
+class LogsWindow(Base, QFrame):
+    """Logs Window."""
+
+    def __init__(
+        self,
+        icon: QIcon,
+    ) -> None:
+        """Initialise the window."""
+        Base.__init__(self)
+        QFrame.__init__(self)
+
+        self._log_path = os.path.join(
+            get_user_data_directory(), "system-bridge-gui.log"
+        )
+        self._position = 0
+
+        self.setWindowTitle("System Bridge - GUI Logs")
+        self.setWindowIcon(icon)
+        self.resize(960, 540)
+
+        self.layout = QVBoxLayout(self)  # type: ignore
+        self.layout.setContentsMargins(0, 0, 0, 0)
+
+        self._text = QPlainTextEdit()
+        self._text.setReadOnly(True)
+        self._text.setMaximumBlockCount(MAX_LINES)
+        self._text.setFont(QFontDatabase.systemFont(QFontDatabase.FixedFont))
+
+        self.layout.addWidget(self._text)
+
+        self._timer = QTimer(self)
+        self._timer.setInterval(1000)
+        self._timer.timeout.connect(self._read_log)  # type: ignore
+
+    # pylint: disable=invalid-name
+    def closeEvent(
+        self,
+        event: QCloseEvent,
+    ) -> None:
+        """Close window instead of closing the app."""
+        event.ignore()
+        self._timer.stop()
+        self.hide()
+
+    def setup(self) -> None:
+        """Set up the logs window."""
+        self._logger.info("Tail log file: %s", self._log_path)
+        self._text.clear()
+        self._position = 0
+        self._read_log()
+        self._timer.start()
+
+    def _read_log(self) -> None:
+        """Append any new lines written to the log file."""
+        try:
+            with open(self._log_path, encoding="utf-8", errors="replace") as file:
+                file.seek(0, os.SEEK_END)
+                if file.tell() < self._position:
+                    # The log file was truncated or rotated
+                    self._text.clear()
+                    self._position = 0
+                file.seek(self._position)
+                lines = file.read()
+                self._position = file.tell()
+        except OSError:
+            return
+
+        if lines:
+            self._text.appendPlainText(lines.rstrip("\n"))
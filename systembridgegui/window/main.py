@@ -1,29 +1,53 @@
 """Main window."""
-from urllib.parse import urlencode
+import html
 
-from PySide6.QtCore import QUrl
-from PySide6.QtGui import QCloseEvent, QIcon
+from PySide6.QtCore import QByteArray, QEvent, QTimer, QUrl, Signal
+from PySide6.QtGui import QCloseEvent, QColor, QGuiApplication, QHideEvent, QIcon, Qt
+from PySide6.QtWebEngineCore import QWebEngineLoadingInfo, QWebEnginePage
 from PySide6.QtWebEngineWidgets import QWebEngineView
-from PySide6.QtWidgets import QFrame, QVBoxLayout
+from PySide6.QtWidgets import QFrame, QMessageBox, QVBoxLayout
 
 from systembridgeshared.base import Base
-from systembridgeshared.const import QUERY_API_PORT, QUERY_TOKEN
 from systembridgeshared.settings import Settings
 
+from ..common import get_app_url, get_background_color, get_theme
+from ..pages import PATH_DATA, get_page_title
+from ..window_state import WindowState
+
+LOAD_RETRY_INTERVAL_MS = 5000
+
+LOAD_FAILED_HTML = """<!DOCTYPE html>
+<html>
+  <body style="background: {background}; color: {color}; font-family: sans-serif;
+    text-align: center; padding-top: 20vh;">
+    <h2>Connecting to System Bridge..</h2>
+    <p>The backend is not reachable yet. Retrying automatically.</p>
+    <p><a href="{url}" style="color: {link_color};">Retry now</a></p>
+  </body>
+</html>
+"""
+
 
 class MainWindow(Base, QFrame):
     """Main Window."""
 
+    exit_requested = Signal()
+
     def __init__(
         self,
         settings: Settings,
         icon: QIcon,
+        window_state: WindowState,
     ) -> None:
         """Initialise the window."""
         Base.__init__(self)
         QFrame.__init__(self)
 
         self._settings = settings
+        self._window_state = window_state
+        self.path: str | None = None
+        self._url: QUrl | None = None
+        self._load_failed = False
 
         self.setWindowTitle("System Bridge")
         self.setWindowIcon(icon)
@@ -32,6 +56,23 @@ class MainWindow(Base, QFrame):
         self.layout.setContentsMargins(0, 0, 0, 0)
 
         self._browser = QWebEngineView()
+        self._devtools: QWebEngineView | None = None
+        self._browser.page().setBackgroundColor(QColor(get_background_color()))
+        self._browser.renderProcessTerminated.connect(  # type: ignore
+            self._render_process_terminated
+        )
+        self._browser.page().loadingChanged.connect(  # type: ignore
+            self._loading_changed
+        )
+
+        self._retry_timer = QTimer(self)
+        self._retry_timer.setSingleShot(True)
+        self._retry_timer.setInterval(LOAD_RETRY_INTERVAL_MS)
+        self._retry_timer.timeout.connect(self._retry_load)  # type: ignore
+
+        self._auto_hide_timer = QTimer(self)
+        self._auto_hide_timer.setSingleShot(True)
+        self._auto_hide_timer.timeout.connect(self._auto_hide)  # type: ignore
 
         self.layout.addWidget(self._browser)
 
@@ -40,20 +81,200 @@ class MainWindow(Base, QFrame):
         self,
         event: QCloseEvent,
     ) -> None:
-        """Close window instead of closing the app."""
+        """Close window instead of closing the app, unless asked not to."""
         event.ignore()
         self.hide()
+        if not self._window_state.get_preference("minimize_to_tray"):
+            self.exit_requested.emit()
+
+    def hideEvent(
+        self,
+        event: QHideEvent,
+    ) -> None:
+        """Save the window geometry when it is hidden."""
+        self._auto_hide_timer.stop()
+        self._window_state.set(
+            self.geometry_key,
+            self.saveGeometry().toBase64().data().decode("ascii"),
+        )
+        QFrame.hideEvent(self, event)
+
+    def changeEvent(
+        self,
+        event: QEvent,
+    ) -> None:
+        """Count down to hiding the data window while it is out of focus."""
+        if event.type() == QEvent.Type.ActivationChange:
+            auto_hide_after_secs = self._window_state.get_preference(
+                "auto_hide_after_secs"
+            )
+            if (
+                self.path == PATH_DATA
+                and self.isVisible()
+                and not self.isActiveWindow()
+                and isinstance(auto_hide_after_secs, int)
+                and auto_hide_after_secs > 0
+            ):
+                self._auto_hide_timer.start(auto_hide_after_secs * 1000)
+            else:
+                self._auto_hide_timer.stop()
+        QFrame.changeEvent(self, event)
+
+    def _auto_hide(self) -> None:
+        """Hide the window to the tray if it is still out of focus."""
+        if self.isVisible() and not self.isActiveWindow():
+            self._logger.info("Hiding idle window: %s", self.path)
+            self.hide()
+
+    def _loading_changed(
+        self,
+        loading_info: QWebEngineLoadingInfo,
+    ) -> None:
+        """Show a local page instead of a browser error when loading fails."""
+        if self._url is None or loading_info.url() != self._url:
+            return
+
+        status = loading_info.status()
+        if status == QWebEngineLoadingInfo.LoadStatus.LoadSucceededStatus:
+            self._load_failed = False
+            self._retry_timer.stop()
+            return
+
+        # Loads replaced by a new navigation or reload stop rather than fail
+        if (
+            status != QWebEngineLoadingInfo.LoadStatus.LoadFailedStatus
+            or loading_info.errorDomain()
+            != QWebEngineLoadingInfo.ErrorDomain.ConnectionErrorDomain
+        ):
+            return
+
+        self._logger.warning(
+            "Could not load URL, retrying: %s (%s)",
+            self.path,
+            loading_info.errorString(),
+        )
+        self._load_failed = True
+        dark = get_theme() == "dark"
+        self._browser.setHtml(
+            LOAD_FAILED_HTML.format(
+                background=get_background_color(),
+                color="#ffffff" if dark else "#212121",
+                link_color="#90caf9" if dark else "#1565c0",
+                url=html.escape(self._url.toString()),
+            )
+        )
+        self._retry_timer.start()
+
+    def _retry_load(self) -> None:
+        """Retry loading the page after a failure."""
+        if not self._load_failed or self._url is None:
+            return
+        self._load_failed = False
+        self._browser.load(self._url)
+
+    def _render_process_terminated(
+        self,
+        status: QWebEnginePage.RenderProcessTerminationStatus,
+        exit_code: int,
+    ) -> None:
+        """Handle the web view render process stopping."""
+        if (
+            status
+            == QWebEnginePage.RenderProcessTerminationStatus.NormalTerminationStatus
+        ):
+            return
+
+        self._logger.error(
+            "Web view render process terminated: %s (%s)", status, exit_code
+        )
+        result = QMessageBox.critical(
+            self,
+            "Error",
+            "The web view stopped unexpectedly. "
+            "Make sure the Qt WebEngine runtime is installed correctly.",
+            QMessageBox.StandardButton.Retry | QMessageBox.StandardButton.Close,
+        )
+        if result == QMessageBox.StandardButton.Retry:
+            self._browser.reload()
+        else:
+            self.hide()
+
+    def open_devtools(self) -> None:
+        """Open the developer tools for the web view."""
+        if self._devtools is None:
+            self._devtools = QWebEngineView()
+            self._devtools.setWindowTitle("System Bridge - Developer Tools")
+            self._devtools.resize(1024, 640)
+            self._browser.page().setDevToolsPage(self._devtools.page())
+        self._devtools.showNormal()
+        self._devtools.activateWindow()
+
+    def set_always_on_top(
+        self,
+        enabled: bool,
+    ) -> None:
+        """Set whether the window stays on top of other windows."""
+        visible = self.isVisible()
+        self.setWindowFlag(Qt.WindowType.WindowStaysOnTopHint, enabled)
+        # Changing window flags hides the window
+        if visible:
+            self.show()
+
+    @property
+    def geometry_key(self) -> str:
+        """Get the window state key for this window's geometry."""
+        return f"geometry:{self.path}"
+
+    def restore_geometry(self) -> bool:
+        """Restore the saved window geometry, if it is still on screen."""
+        geometry = self._window_state.get(self.geometry_key)
+        if not isinstance(geometry, str) or not geometry:
+            return False
+
+        if not self.restoreGeometry(QByteArray.fromBase64(geometry.encode("ascii"))):
+            self._logger.warning("Could not restore window geometry")
+            return False
+
+        # The monitor the window was on may have been disconnected since
+        if QGuiApplication.screenAt(self.frameGeometry().center()) is None:
+            self._logger.info("Saved window position is off screen, using defaults")
+            return False
+        return True
+
+    def capture(
+        self,
+        path: str,
+    ) -> bool:
+        """Save a screenshot of the window contents."""
+        self._logger.info("Save screenshot: %s", path)
+        return self._browser.grab().save(path, "PNG")
+
+    def reload(self) -> None:
+        """Reload the current page."""
+        if self._load_failed:
+            self._retry_load()
+            return
+        self._browser.reload()
+
+    def update_title(self) -> None:
+        """Set the window title for the current page."""
+        if self.path is None:
+            return
+        self.setWindowTitle(
+            get_page_title(self.path, self._window_state.get_preference("title_suffix"))
+        )
 
     def setup(
         self,
         path: str,
     ) -> None:
         """Set up the main window."""
-        url = QUrl(
-            f"""http://localhost:{self._settings.data.api.port}{path}?{urlencode({
-                    QUERY_TOKEN: self._settings.data.api.token,
-                    QUERY_API_PORT: self._settings.data.api.port,
-                })}"""
-        )
-        self._logger.info("Open URL: %s", url)
-        self._browser.load(url)
+        self.path = path
+        self.update_title()
+        # The OS colour scheme may have changed since the window was created
+        self._browser.page().setBackgroundColor(QColor(get_background_color()))
+        self._url = QUrl(get_app_url(self._settings, path))
+        self._load_failed = False
+        self._retry_timer.stop()
+        self._logger.info("Open URL: %s", self._url)
+        self._browser.load(self._url)
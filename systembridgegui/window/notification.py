@@ -2,18 +2,18 @@
 from dataclasses import asdict
 from json import dumps
 import sys
-from urllib.parse import urlencode
 
 from PySide6.QtCore import QTimer, QUrl
-from PySide6.QtGui import QIcon, Qt
+from PySide6.QtGui import QColor, QIcon, Qt
 from PySide6.QtWebEngineWidgets import QWebEngineView
 from PySide6.QtWidgets import QApplication, QFrame, QVBoxLayout
 
 from systembridgemodels.notification import Notification
 from systembridgeshared.base import Base
-from systembridgeshared.const import QUERY_API_PORT, QUERY_TOKEN
 from systembridgeshared.settings import Settings
 
+from ..common import get_app_url, get_background_color
+
 
 class NotificationWindow(Base, QFrame):
     """Notification Window."""
@@ -38,6 +38,7 @@ class NotificationWindow(Base, QFrame):
         self.layout.setContentsMargins(0, 0, 0, 0)  # type: ignore
 
         self.browser = QWebEngineView()
+        self.browser.page().setBackgroundColor(QColor(get_background_color()))
 
         self.layout.addWidget(self.browser)  # type: ignore
 
@@ -76,11 +77,11 @@ class NotificationWindow(Base, QFrame):
             notification_dict["actions"] = dumps(notification_dict["actions"])
 
         url = QUrl(
-            f"""http://localhost:{self._settings.data.api.port}/app/notification.html?{urlencode({
-                    QUERY_TOKEN: self._settings.data.api.token,
-                    QUERY_API_PORT: self._settings.data.api.port,
-                    **notification_dict,
-                })}"""
+            get_app_url(
+                self._settings,
+                "/app/notification.html",
+                notification_dict,
+            )
         )
         self._logger.info("Open URL: %s", url)
         self.browser.load(url)
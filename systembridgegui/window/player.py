@@ -1,18 +1,18 @@
 """Player Window."""
 from dataclasses import asdict
 import sys
-from urllib.parse import urlencode
 
 from PySide6.QtCore import QUrl
-from PySide6.QtGui import QIcon, Qt
+from PySide6.QtGui import QColor, QIcon, Qt
 from PySide6.QtWebEngineWidgets import QWebEngineView
 from PySide6.QtWidgets import QApplication, QFrame, QVBoxLayout
 
 from systembridgemodels.media_play import MediaPlay
 from systembridgeshared.base import Base
-from systembridgeshared.const import QUERY_API_PORT, QUERY_TOKEN
 from systembridgeshared.settings import Settings
 
+from ..common import get_app_url, get_background_color
+
 
 class PlayerWindow(Base, QFrame):
     """Player Window."""
@@ -38,6 +38,7 @@ class PlayerWindow(Base, QFrame):
         self.layout.setContentsMargins(0, 0, 0, 0)  # type: ignore
 
         self.browser = QWebEngineView()
+        self.browser.page().setBackgroundColor(QColor(get_background_color()))
 
         self.layout.addWidget(self.browser)  # type: ignore
 
@@ -57,11 +58,11 @@ class PlayerWindow(Base, QFrame):
         )
 
         url = QUrl(
-            f"""http://localhost:{self._settings.data.api.port}/app/player/{media_type}.html?{urlencode({
-                    QUERY_TOKEN: self._settings.data.api.token,
-                    QUERY_API_PORT: self._settings.data.api.port,
-                    **asdict(media_play),
-                })}"""
+            get_app_url(
+                self._settings,
+                f"/app/player/{media_type}.html",
+                asdict(media_play),
+            )
         )
         self._logger.info("Open URL: %s", url)
         self.browser.load(url)
@@ -0,0 +1,90 @@
+"""Popup Window."""
+from PySide6.QtCore import QUrl
+from PySide6.QtGui import QCloseEvent, QColor, QIcon, QKeyEvent, Qt
+from PySide6.QtWebEngineWidgets import QWebEngineView
+from PySide6.QtWidgets import QApplication, QFrame, QVBoxLayout
+
+from systembridgeshared.base import Base
+from systembridgeshared.settings import Settings
+
+from ..common import get_app_url, get_background_color
+from ..pages import get_page_title
+
+
+class PopupWindow(Base, QFrame):
+    """Small undecorated window showing a page on top of other windows."""
+
+    def __init__(
+        self,
+        settings: Settings,
+        icon: QIcon,
+        application: QApplication,
+        path: str,
+        width: int,
+        height: int,
+    ) -> None:
+        """Initialise the window."""
+        Base.__init__(self)
+        QFrame.__init__(
+            self,
+            WindowFlags=Qt.FramelessWindowHint | Qt.WindowStaysOnTopHint,  # type: ignore
+        )
+
+        self._application = application
+
+        # Label popups apart from the page windows in task switchers
+        self.setWindowTitle(get_page_title(path, "Popup"))
+        self.setWindowIcon(icon)
+
+        self.layout = QVBoxLayout(self)  # type: ignore
+        self.layout.setContentsMargins(0, 0, 0, 0)  # type: ignore
+
+        self.browser = QWebEngineView()
+        self.browser.page().setBackgroundColor(QColor(get_background_color()))
+        self.browser.urlChanged.connect(self._url_changed)  # type: ignore
+        self.layout.addWidget(self.browser)  # type: ignore
+
+        # Keep the popup on screen, in the corner like notifications
+        screen_geometry = application.primaryScreen().availableSize()
+        self.resize(
+            min(width, screen_geometry.width()),
+            min(height, screen_geometry.height()),
+        )
+        self.move(
+            screen_geometry.width() - self.width() - 8,
+            screen_geometry.height() - self.height() - 8,
+        )
+
+        url = QUrl(get_app_url(settings, path))
+        self._logger.info("Open URL: %s", url)
+        self.browser.load(url)
+
+        self.showNormal()
+
+    # pylint: disable=invalid-name
+    def closeEvent(
+        self,
+        event: QCloseEvent,
+    ) -> None:
+        """Exit, as the popup runs in its own process."""
+        event.accept()
+        self._application.quit()
+
+    def keyPressEvent(
+        self,
+        event: QKeyEvent,
+    ) -> None:
+        """Close the popup on Escape, as it has no title bar."""
+        if event.key() == Qt.Key.Key_Escape:
+            self.close()
+            return
+        QFrame.keyPressEvent(self, event)
+
+    def _url_changed(
+        self,
+        url: QUrl,
+    ) -> None:
+        """Close the popup when the page asks to."""
+        if url.host() == "close.window":
+            self._logger.info("Close Window Requested. Closing Window.")
+            self.close()
@@ -0,0 +1,107 @@
+"""Window State."""
+from __future__ import annotations
+
+import json
+import os
+from typing import Any
+
+from systembridgeshared.base import Base
+from systembridgeshared.common import get_user_data_directory
+
+from .const import WINDOW_HEIGHT, WINDOW_WIDTH
+
+# Preferences kept with the window state, as the settings model is shared with
+# the backend. Values are the defaults used until one is changed from the tray.
+PREFERENCES: dict[str, Any] = {
+    "confirm_exit": False,
+    "raise_on_show": True,
+    "minimize_to_tray": True,
+    "notifications_enabled": True,
+    "window_size": [WINDOW_WIDTH, WINDOW_HEIGHT],
+    "auto_hide_after_secs": 0,
+    "tray_stats": False,
+    "open_on_startup": False,
+    "file_logging": True,
+    "title_suffix": "System Bridge",
+}
+
+
+class WindowState(Base):
+    """Persisted window state, stored next to the settings file."""
+
+    def __init__(
+        self,
+        ignore_saved: bool = False,
+    ) -> None:
+        """Initialise."""
+        super().__init__()
+        self._path = os.path.join(get_user_data_directory(), "window-state.json")
+        # In safe mode changes only last for the session, keeping the file intact
+        self._ignore_saved = ignore_saved
+        self._data: dict[str, Any] = {} if ignore_saved else self._load()
+
+    def _load(self) -> dict[str, Any]:
+        """Load the window state from disk."""
+        try:
+            with open(self._path, encoding="utf-8") as file:
+                data = json.load(file)
+        except FileNotFoundError:
+            return {}
+        except (OSError, ValueError) as exception:
+            self._logger.warning("Could not load window state: %s", exception)
+            return {}
+
+        if not isinstance(data, dict):
+            self._logger.warning("Ignoring invalid window state")
+            return {}
+        return data
+
+    def _save(self) -> None:
+        """Save the window state to disk."""
+        if self._ignore_saved:
+            return
+        try:
+            with open(self._path, "w", encoding="utf-8") as file:
+                json.dump(self._data, file, indent=2)
+        except OSError as exception:
+            self._logger.warning("Could not save window state: %s", exception)
+
+    def get(
+        self,
+        key: str,
+        default: Any = None,
+    ) -> Any:
+        """Get a value from the window state."""
+        return self._data.get(key, default)
+
+    def get_preference(
+        self,
+        key: str,
+    ) -> Any:
+        """Get a preference, or its default if it is unset or the wrong type."""
+        default = PREFERENCES[key]
+        value = self._data.get(key, default)
+        # The file can be edited by hand, so don't trust what it holds
+        if not isinstance(value, type(default)):
+            self._logger.warning("Ignoring invalid %s preference: %s", key, value)
+            return default
+        return value
+
+    def set(
+        self,
+        key: str,
+        value: Any,
+    ) -> None:
+        """Set and persist a value in the window state."""
+        self._data[key] = value
+        self._save()
+
+    def reset(self) -> None:
+        """Clear the window state and remove it from disk."""
+        self._data = {}
+        try:
+            os.remove(self._path)
+        except FileNotFoundError:
+            pass
+        except OSError as exception:
+            self._logger.warning("Could not remove window state: %s", exception)
@@ -0,0 +1,47 @@
+"""Test the common helpers."""
+from __future__ import annotations
+
+import pytest
+
+from systembridgegui.common import fit_aspect_ratio, parse_aspect_ratio
+
+
+@pytest.mark.parametrize(
+    ("value", "expected"),
+    [
+        ("16:9", 16 / 9),
+        (" 4:3 ", 4 / 3),
+        ("1.5", 1.5),
+        ("9:16", 9 / 16),
+    ],
+)
+def test_parse_aspect_ratio(
+    value: str,
+    expected: float,
+) -> None:
+    """Test aspect ratios are parsed from both forms."""
+    assert parse_aspect_ratio(value) == pytest.approx(expected)
+
+
+@pytest.mark.parametrize(
+    "value",
+    ["", "wide", "16:", ":9", "16:0", "0", "-1", "-16:9", "inf", "nan"],
+)
+def test_parse_aspect_ratio_invalid(value: str) -> None:
+    """Test invalid aspect ratios are rejected."""
+    assert parse_aspect_ratio(value) is None
+
+
+def test_fit_aspect_ratio_wider() -> None:
+    """Test a wider ratio keeps the width and reduces the height."""
+    assert fit_aspect_ratio(1280, 720, 21 / 9) == (1280, 549)
+
+
+def test_fit_aspect_ratio_taller() -> None:
+    """Test a taller ratio keeps the height and reduces the width."""
+    assert fit_aspect_ratio(1280, 720, 9 / 16) == (405, 720)
+
+
+def test_fit_aspect_ratio_same() -> None:
+    """Test a matching ratio keeps the size."""
+    assert fit_aspect_ratio(1280, 720, 16 / 9) == (1280, 720)
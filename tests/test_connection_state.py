@@ -0,0 +1,227 @@
+"""Test the connection state machine."""
+from __future__ import annotations
+
+import pytest
+
+from systembridgegui.connection_state import (
+    RECONNECT_INTERVAL,
+    RECONNECT_INTERVAL_MAX,
+    RECONNECT_JITTER,
+    RECONNECT_MAX_ATTEMPTS,
+    TRANSITIONS,
+    ConnectionState,
+    ConnectionStateMachine,
+    get_reconnect_delay,
+)
+
+
+class Recorder:
+    """Record state machine callbacks."""
+
+    def __init__(self) -> None:
+        """Initialise."""
+        self.calls: list[tuple[ConnectionState, ConnectionState]] = []
+
+    def __call__(
+        self,
+        previous_state: ConnectionState,
+        state: ConnectionState,
+    ) -> None:
+        """Record a callback."""
+        self.calls.append((previous_state, state))
+
+
+def _machine_in(
+    state: ConnectionState,
+    recorder: Recorder,
+) -> ConnectionStateMachine:
+    """Create a state machine and move it to the given state."""
+    machine = ConnectionStateMachine(recorder)
+    path = {
+        ConnectionState.STARTING: [],
+        ConnectionState.CONNECTED: [ConnectionState.CONNECTED],
+        ConnectionState.DOWN: [ConnectionState.DOWN],
+        ConnectionState.RECONNECTING: [
+            ConnectionState.DOWN,
+            ConnectionState.RECONNECTING,
+        ],
+        ConnectionState.STOPPED: [ConnectionState.STOPPED],
+    }[state]
+    for step in path:
+        assert machine.transition(step)
+    assert machine.state == state
+    recorder.calls.clear()
+    return machine
+
+
+def test_starts_in_starting() -> None:
+    """Test the machine starts before any connection attempt."""
+    machine = ConnectionStateMachine(Recorder())
+    assert machine.state == ConnectionState.STARTING
+    assert not machine.has_connected
+
+
+def test_every_state_has_transitions() -> None:
+    """Test the transition table covers every state."""
+    assert set(TRANSITIONS) == set(ConnectionState)
+
+
+@pytest.mark.parametrize(
+    ("previous_state", "state"),
+    [
+        (previous_state, state)
+        for previous_state, states in TRANSITIONS.items()
+        for state in states
+    ],
+)
+def test_allowed_transitions(
+    previous_state: ConnectionState,
+    state: ConnectionState,
+) -> None:
+    """Test allowed transitions change state and call back."""
+    recorder = Recorder()
+    machine = _machine_in(previous_state, recorder)
+
+    assert machine.transition(state)
+    assert machine.state == state
+    assert recorder.calls == [(previous_state, state)]
+
+
+@pytest.mark.parametrize(
+    ("previous_state", "state"),
+    [
+        (previous_state, state)
+        for previous_state in ConnectionState
+        for state in ConnectionState
+        if state != previous_state and state not in TRANSITIONS[previous_state]
+    ],
+)
+def test_rejected_transitions(
+    previous_state: ConnectionState,
+    state: ConnectionState,
+) -> None:
+    """Test rejected transitions leave the state alone."""
+    recorder = Recorder()
+    machine = _machine_in(previous_state, recorder)
+
+    assert not machine.transition(state)
+    assert machine.state == previous_state
+    assert not recorder.calls
+
+
+def test_starting_to_reconnecting_rejected() -> None:
+    """Test the first attempt is not reported as a reconnect."""
+    recorder = Recorder()
+    machine = ConnectionStateMachine(recorder)
+
+    assert not machine.transition(ConnectionState.RECONNECTING)
+    assert machine.state == ConnectionState.STARTING
+    assert not recorder.calls
+
+
+@pytest.mark.parametrize("state", list(ConnectionState))
+def test_same_state_ignored(state: ConnectionState) -> None:
+    """Test moving to the current state does nothing."""
+    recorder = Recorder()
+    machine = _machine_in(state, recorder)
+
+    assert not machine.transition(state)
+    assert machine.state == state
+    assert not recorder.calls
+
+
+def test_stopped_is_final() -> None:
+    """Test nothing leaves the stopped state."""
+    recorder = Recorder()
+    machine = _machine_in(ConnectionState.STOPPED, recorder)
+
+    for state in ConnectionState:
+        assert not machine.transition(state)
+    assert machine.state == ConnectionState.STOPPED
+    assert not recorder.calls
+
+
+def test_has_connected() -> None:
+    """Test has_connected stays set after the connection drops."""
+    machine = ConnectionStateMachine(Recorder())
+
+    machine.transition(ConnectionState.DOWN)
+    assert not machine.has_connected
+
+    machine.transition(ConnectionState.RECONNECTING)
+    machine.transition(ConnectionState.CONNECTED)
+    assert machine.has_connected
+
+    machine.transition(ConnectionState.DOWN)
+    assert machine.has_connected
+
+
+def test_reconnect_cycle() -> None:
+    """Test a dropped connection recovering."""
+    recorder = Recorder()
+    machine = ConnectionStateMachine(recorder)
+
+    for state in (
+        ConnectionState.CONNECTED,
+        ConnectionState.DOWN,
+        ConnectionState.RECONNECTING,
+        ConnectionState.DOWN,
+        ConnectionState.RECONNECTING,
+        ConnectionState.CONNECTED,
+    ):
+        assert machine.transition(state)
+
+    assert recorder.calls == [
+        (ConnectionState.STARTING, ConnectionState.CONNECTED),
+        (ConnectionState.CONNECTED, ConnectionState.DOWN),
+        (ConnectionState.DOWN, ConnectionState.RECONNECTING),
+        (ConnectionState.RECONNECTING, ConnectionState.DOWN),
+        (ConnectionState.DOWN, ConnectionState.RECONNECTING),
+        (ConnectionState.RECONNECTING, ConnectionState.CONNECTED),
+    ]
+
+
+def _no_jitter(
+    low: float,
+    high: float,
+) -> float:
+    """Return the midpoint of the jitter range."""
+    return (low + high) / 2
+
+
+def test_reconnect_delay_doubles() -> None:
+    """Test the reconnect delay doubles after each failure."""
+    delays = [get_reconnect_delay(attempts, _no_jitter) for attempts in range(4)]
+    assert delays == [
+        RECONNECT_INTERVAL,
+        RECONNECT_INTERVAL * 2,
+        RECONNECT_INTERVAL * 4,
+        RECONNECT_INTERVAL * 8,
+    ]
+
+
+def test_reconnect_delay_capped() -> None:
+    """Test the reconnect delay stops growing at the maximum."""
+    assert (
+        get_reconnect_delay(RECONNECT_MAX_ATTEMPTS - 1, _no_jitter)
+        == RECONNECT_INTERVAL_MAX
+    )
+
+
+def test_reconnect_delay_jitter() -> None:
+    """Test the jitter range is applied to the delay."""
+    assert get_reconnect_delay(0, lambda low, _: low) == pytest.approx(
+        RECONNECT_INTERVAL * (1 - RECONNECT_JITTER)
+    )
+    assert get_reconnect_delay(0, lambda _, high: high) == pytest.approx(
+        RECONNECT_INTERVAL * (1 + RECONNECT_JITTER)
+    )
+
+
+@pytest.mark.parametrize(
+    "attempts",
+    [RECONNECT_MAX_ATTEMPTS, RECONNECT_MAX_ATTEMPTS + 1, RECONNECT_MAX_ATTEMPTS * 10],
+)
+def test_reconnect_keeps_retrying(attempts: int) -> None:
+    """Test reconnecting carries on at the maximum interval."""
+    assert get_reconnect_delay(attempts, _no_jitter) == RECONNECT_INTERVAL_MAX
@@ -0,0 +1,74 @@
+"""Test the command line settings loader."""
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+from systembridgegui import __main__ as main_module
+from systembridgegui.__main__ import SettingsUnavailable, _read_settings
+from systembridgegui.const import (
+    EXIT_CODE_SETTINGS_INVALID,
+    EXIT_CODE_SETTINGS_MISSING,
+)
+
+
+def _use_data_directory(
+    monkeypatch: pytest.MonkeyPatch,
+    path: Path,
+) -> None:
+    """Point the loader at a temporary data directory."""
+    monkeypatch.setattr(main_module, "get_user_data_directory", lambda: str(path))
+
+
+def test_read_settings_missing(
+    monkeypatch: pytest.MonkeyPatch,
+    tmp_path: Path,
+) -> None:
+    """Test a missing settings file is reported with its own exit code."""
+    _use_data_directory(monkeypatch, tmp_path)
+
+    with pytest.raises(SettingsUnavailable) as exception_info:
+        _read_settings()
+
+    assert exception_info.value.exit_code == EXIT_CODE_SETTINGS_MISSING
+    assert str(tmp_path / "settings.json") in str(exception_info.value)
+
+
+@pytest.mark.parametrize(
+    "error",
+    [KeyError("api"), OSError("denied"), TypeError("port"), ValueError("json")],
+)
+def test_read_settings_invalid(
+    monkeypatch: pytest.MonkeyPatch,
+    tmp_path: Path,
+    error: Exception,
+) -> None:
+    """Test a settings file that can't be read is reported as invalid."""
+    _use_data_directory(monkeypatch, tmp_path)
+    (tmp_path / "settings.json").write_text("{", encoding="utf-8")
+
+    def _settings() -> None:
+        """Fail to read the settings."""
+        raise error
+
+    monkeypatch.setattr(main_module, "Settings", _settings)
+
+    with pytest.raises(SettingsUnavailable) as exception_info:
+        _read_settings()
+
+    assert exception_info.value.exit_code == EXIT_CODE_SETTINGS_INVALID
+    assert exception_info.value.__cause__ is error
+
+
+def test_read_settings_valid(
+    monkeypatch: pytest.MonkeyPatch,
+    tmp_path: Path,
+) -> None:
+    """Test the settings are returned when the file can be read."""
+    _use_data_directory(monkeypatch, tmp_path)
+    (tmp_path / "settings.json").write_text("{}", encoding="utf-8")
+    settings = object()
+    monkeypatch.setattr(main_module, "Settings", lambda: settings)
+
+    assert _read_settings() is settings
@@ -0,0 +1,39 @@
+"""Test the settings watcher."""
+from __future__ import annotations
+
+from types import SimpleNamespace
+
+import pytest
+
+from systembridgegui.settings_watcher import is_valid_settings
+
+
+def _settings(
+    port: int,
+    token: str,
+) -> SimpleNamespace:
+    """Build settings with only the fields the check reads."""
+    return SimpleNamespace(
+        data=SimpleNamespace(api=SimpleNamespace(port=port, token=token))
+    )
+
+
+@pytest.mark.parametrize(
+    ("port", "token", "expected"),
+    [
+        (9170, "abc123", True),
+        (1, "abc123", True),
+        (65535, "abc123", True),
+        (0, "abc123", False),
+        (-1, "abc123", False),
+        (65536, "abc123", False),
+        (9170, "", False),
+    ],
+)
+def test_is_valid_settings(
+    port: int,
+    token: str,
+    expected: bool,
+) -> None:
+    """Test settings without a usable port or token are rejected."""
+    assert is_valid_settings(_settings(port, token)) is expected